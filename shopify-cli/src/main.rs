@@ -1,3 +1,4 @@
+use chrono::{NaiveDate, TimeZone, Utc};
 use clap::Parser;
 use shopify::client::Client;
 use std::env::var;
@@ -21,6 +22,10 @@ enum SubCommand {
   OrderFulfill(OrderFulfill),
   OrderFulfillmentUpdateTracking(OrderFulfillmentUpdateTracking),
   LocationList,
+  TenderTransactionExport(TenderTransactionExport),
+  OnlineStoreSync(OnlineStoreSync),
+  ThemePull(ThemeSync),
+  ThemePush(ThemeSync),
 }
 
 #[derive(Parser)]
@@ -35,6 +40,11 @@ struct OrderFulfill {
   location_id: i64,
   carrier: String,
   tracking_number: String,
+  #[clap(long, default_value = "1")]
+  quantity: i64,
+  /// Tracking URL for carriers Shopify doesn't link, `{number}` is replaced with the tracking number.
+  #[clap(long)]
+  tracking_url_template: Option<String>,
 }
 
 #[derive(Parser)]
@@ -42,6 +52,44 @@ struct OrderFulfillmentUpdateTracking {
   id: i64,
   carrier: String,
   tracking_number: String,
+  /// Tracking URL for carriers Shopify doesn't link, `{number}` is replaced with the tracking number.
+  #[clap(long)]
+  tracking_url_template: Option<String>,
+}
+
+/// Exports the tender transactions processed between two dates (inclusive, UTC) as CSV.
+#[derive(Parser)]
+struct TenderTransactionExport {
+  from: NaiveDate,
+  to: NaiveDate,
+  /// Writes to stdout if not set.
+  #[clap(short, long)]
+  output: Option<String>,
+}
+
+/// Creates or updates pages from a directory of `.md` / `.html` files and redirects from a CSV.
+///
+/// A page's handle is its file stem. Markdown pages take their title from the first `# ` heading.
+/// The redirect CSV has a `path,target` header. Nothing is deleted.
+#[derive(Parser)]
+struct OnlineStoreSync {
+  #[clap(long)]
+  pages: Option<String>,
+  #[clap(long)]
+  redirects: Option<String>,
+  /// Prints the changes without applying them.
+  #[clap(long)]
+  dry_run: bool,
+}
+
+/// Mirrors the assets of a theme to (pull) or from (push) a local directory.
+#[derive(Parser)]
+struct ThemeSync {
+  theme_id: i64,
+  dir: String,
+  /// Deletes theme assets (push) or local files (pull) that only exist on the other side.
+  #[clap(long)]
+  delete: bool,
 }
 
 fn main() {
@@ -69,6 +117,17 @@ fn main() {
       order_fulfillment_update_tracking(&client, &update)
     }
     SubCommand::LocationList => location_list(&client),
+    SubCommand::TenderTransactionExport(export) => tender_transaction_export(&client, &export),
+    SubCommand::OnlineStoreSync(sync) => {
+      if let Some(ref dir) = sync.pages {
+        page_sync(&client, dir, sync.dry_run)
+      }
+      if let Some(ref path) = sync.redirects {
+        redirect_sync(&client, path, sync.dry_run)
+      }
+    }
+    SubCommand::ThemePull(sync) => theme_pull(&client, &sync),
+    SubCommand::ThemePush(sync) => theme_push(&client, &sync),
   }
 }
 
@@ -157,50 +216,24 @@ fn order_list(client: &Client) {
 fn order_fulfill(client: &Client, fulfill: &OrderFulfill) {
   use shopify::order::*;
 
-  let fos = client.get_fulfillment_orders(fulfill.id).unwrap();
-  dbg!(&fos);
-  let (fo, li) = fos
-    .iter()
-    .filter(|fo| fo.status == FulfillmentOrderStatus::Open)
-    .find_map(|fo| {
-      fo.line_items
-        .iter()
-        .find(|li| li.line_item_id == fulfill.item_id)
-        .map(|li| (fo, li))
-    })
-    .expect("No matching fulfillment order");
-  if fo.assigned_location_id != Some(fulfill.location_id) {
-    println!(
-      "Moving fulfillment order {} to location {}",
-      fo.id, fulfill.location_id
-    );
-    client
-      .move_fulfillment_order(
-        fo.id,
-        &MoveFulfillmentOrderRequest {
-          new_location_id: fulfill.location_id,
-          fulfillment_order_line_items: None,
-        },
-      )
-      .unwrap();
+  let order = client.get(fulfill.id).unwrap();
+  let mut tracking_info = TrackingInfo::new(&fulfill.carrier, &fulfill.tracking_number).unwrap();
+  if let Some(ref template) = fulfill.tracking_url_template {
+    tracking_info = tracking_info.url_template(template);
   }
-
   let r = client
-    .create_fulfillment(&CreateFulfillmentRequest {
-      line_items_by_fulfillment_order: vec![LineItemsByFulfillmentOrder {
-        fulfillment_order_id: fo.id,
-        fulfillment_order_line_items: vec![FulfillmentOrderLineItems {
-          id: li.id,
-          quantity: 1,
+    .fulfill(
+      &order,
+      &FulfillRequest {
+        items: vec![FulfillItem {
+          item: FulfillItemRef::LineItem(fulfill.item_id),
+          quantity: fulfill.quantity,
+          location_id: fulfill.location_id,
         }],
-      }],
-      notify_customer: Some(true),
-      tracking_info: Some(TrackingInfo {
-        number: fulfill.tracking_number.clone(),
-        company: fulfill.carrier.clone(),
-        url: None,
-      }),
-    })
+        tracking_info: Some(tracking_info),
+        notify_customer: Some(true),
+      },
+    )
     .unwrap();
 
   serde_json::to_writer_pretty(std::io::stdout(), &r).unwrap()
@@ -208,16 +241,12 @@ fn order_fulfill(client: &Client, fulfill: &OrderFulfill) {
 
 fn order_fulfillment_update_tracking(client: &Client, update: &OrderFulfillmentUpdateTracking) {
   use shopify::order::*;
+  let mut tracking_info = TrackingInfo::new(&update.carrier, &update.tracking_number).unwrap();
+  if let Some(ref template) = update.tracking_url_template {
+    tracking_info = tracking_info.url_template(template);
+  }
   client
-    .update_fulfillment_tracking(
-      update.id,
-      &TrackingInfo {
-        number: update.tracking_number.clone(),
-        company: update.carrier.clone(),
-        url: None,
-      },
-      true,
-    )
+    .update_fulfillment_tracking(update.id, &tracking_info, true)
     .unwrap();
 }
 
@@ -226,3 +255,307 @@ fn location_list(client: &Client) {
 
   serde_json::to_writer_pretty(std::io::stdout(), &client.get_list().unwrap()).unwrap()
 }
+
+fn tender_transaction_export(client: &Client, export: &TenderTransactionExport) {
+  use shopify::tender_transaction::*;
+
+  let params = GetTenderTransactionListParams {
+    limit: Some(250),
+    processed_at_min: Some(Utc.from_utc_datetime(&export.from.and_hms_opt(0, 0, 0).unwrap())),
+    processed_at_max: Some(
+      Utc.from_utc_datetime(&export.to.and_hms_milli_opt(23, 59, 59, 999).unwrap()),
+    ),
+    order: Some("processed_at ASC".to_owned()),
+    ..Default::default()
+  };
+  let transactions = client
+    .list(&params)
+    .unwrap()
+    .into_iter_with(|page| client.list_page(&page.clone().limit(250)));
+
+  let out: Box<dyn std::io::Write> = match export.output {
+    Some(ref path) => Box::new(std::fs::File::create(path).unwrap()),
+    None => Box::new(std::io::stdout()),
+  };
+  let mut w = csv::Writer::from_writer(out);
+  w.write_record([
+    "id",
+    "order_id",
+    "processed_at",
+    "amount",
+    "currency",
+    "payment_method",
+    "credit_card_company",
+    "remote_reference",
+    "test",
+  ])
+  .unwrap();
+  for t in transactions {
+    let t = t.unwrap();
+    let details = t.payment_details.as_ref();
+    w.write_record([
+      t.id.to_string(),
+      t.order_id.to_string(),
+      t.processed_at.to_rfc3339(),
+      t.amount.clone(),
+      t.currency.clone(),
+      t.payment_method.clone().unwrap_or_default(),
+      details
+        .and_then(|d| d.credit_card_company.clone())
+        .unwrap_or_default(),
+      t.remote_reference.clone().unwrap_or_default(),
+      t.test.to_string(),
+    ])
+    .unwrap();
+  }
+  w.flush().unwrap();
+}
+
+fn page_sync(client: &Client, dir: &str, dry_run: bool) {
+  use shopify::page::*;
+  use std::collections::HashMap;
+
+  let mut pages: HashMap<String, Page> = client
+    .list(&GetPageListParams {
+      limit: Some(250),
+      ..Default::default()
+    })
+    .unwrap()
+    .into_iter_with(|page| client.list_page(&page.clone().limit(250)))
+    .map(|p| p.unwrap())
+    .map(|p| (p.handle.clone(), p))
+    .collect();
+
+  let mut entries: Vec<_> = std::fs::read_dir(dir)
+    .unwrap()
+    .map(|e| e.unwrap().path())
+    .collect();
+  entries.sort();
+
+  for path in entries {
+    let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    let handle = path.file_stem().unwrap().to_string_lossy().into_owned();
+    let source = std::fs::read_to_string(&path).unwrap();
+    let (title, body_html) = match ext {
+      "md" | "markdown" => render_markdown(&source),
+      "html" | "htm" => (None, source),
+      _ => continue,
+    };
+
+    match pages.remove(&handle) {
+      Some(page) => {
+        let title_changed = title.as_ref().is_some_and(|t| *t != page.title);
+        if !title_changed && page.body_html.as_deref() == Some(body_html.as_str()) {
+          continue;
+        }
+        println!("update page: {}", handle);
+        if !dry_run {
+          let mut value = serde_json::json!({ "body_html": body_html });
+          if let Some(title) = title {
+            value["title"] = title.into();
+          }
+          client.update(page.id, value).unwrap();
+        }
+      }
+      None => {
+        println!("create page: {}", handle);
+        if !dry_run {
+          client
+            .create(&NewPage {
+              title: title.unwrap_or_else(|| handle.clone()),
+              handle: Some(handle),
+              body_html: Some(body_html),
+              ..Default::default()
+            })
+            .unwrap();
+        }
+      }
+    }
+  }
+}
+
+/// Renders markdown to HTML, taking the first `# ` heading out as the title.
+fn render_markdown(source: &str) -> (Option<String>, String) {
+  let mut title = None;
+  let mut body = String::new();
+  for line in source.lines() {
+    match line.strip_prefix("# ") {
+      Some(heading) if title.is_none() => title = Some(heading.trim().to_owned()),
+      _ => {
+        body.push_str(line);
+        body.push('\n');
+      }
+    }
+  }
+
+  let mut html = String::new();
+  pulldown_cmark::html::push_html(&mut html, pulldown_cmark::Parser::new(&body));
+  (title, html)
+}
+
+fn redirect_sync(client: &Client, path: &str, dry_run: bool) {
+  use shopify::redirect::*;
+  use std::collections::HashMap;
+
+  #[derive(serde::Deserialize)]
+  struct Row {
+    path: String,
+    target: String,
+  }
+
+  let redirects: HashMap<String, Redirect> = client
+    .list(&GetRedirectListParams {
+      limit: Some(250),
+      ..Default::default()
+    })
+    .unwrap()
+    .into_iter_with(|page| client.list_page(&page.clone().limit(250)))
+    .map(|r| r.unwrap())
+    .map(|r| (r.path.clone(), r))
+    .collect();
+
+  let mut r = csv::Reader::from_path(path).unwrap();
+  for row in r.deserialize::<Row>() {
+    let row = row.unwrap();
+    match redirects.get(&row.path) {
+      Some(redirect) if redirect.target == row.target => {}
+      Some(redirect) => {
+        println!("update redirect: {} -> {}", row.path, row.target);
+        if !dry_run {
+          client
+            .update(redirect.id, serde_json::json!({ "target": row.target }))
+            .unwrap();
+        }
+      }
+      None => {
+        println!("create redirect: {} -> {}", row.path, row.target);
+        if !dry_run {
+          client
+            .create(&NewRedirect::new(&row.path, &row.target))
+            .unwrap();
+        }
+      }
+    }
+  }
+}
+
+fn theme_pull(client: &Client, sync: &ThemeSync) {
+  use base64::engine::general_purpose::STANDARD as BASE64;
+  use base64::Engine;
+  use shopify::theme::*;
+  use std::collections::HashSet;
+  use std::path::Path;
+
+  let root = Path::new(&sync.dir);
+  let mut keys = HashSet::new();
+  for asset in client.list_assets(sync.theme_id).unwrap() {
+    let path = match theme_asset_path(root, &asset.key) {
+      Some(path) => path,
+      None => {
+        eprintln!("skip {}: key is outside of the theme directory", asset.key);
+        continue;
+      }
+    };
+    keys.insert(asset.key.clone());
+    let asset = client.get_asset(sync.theme_id, &asset.key).unwrap();
+    let content = match (asset.value, asset.attachment) {
+      (Some(value), _) => value.into_bytes(),
+      (None, Some(attachment)) => BASE64.decode(attachment).unwrap(),
+      (None, None) => continue,
+    };
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, content).unwrap();
+    println!("pull: {}", asset.key);
+  }
+
+  if sync.delete {
+    for (key, path) in theme_files(root) {
+      if !keys.contains(&key) {
+        std::fs::remove_file(&path).unwrap();
+        println!("delete: {}", key);
+      }
+    }
+  }
+}
+
+fn theme_push(client: &Client, sync: &ThemeSync) {
+  use base64::engine::general_purpose::STANDARD as BASE64;
+  use base64::Engine;
+  use shopify::theme::*;
+  use std::collections::HashSet;
+  use std::path::Path;
+
+  let mut keys = HashSet::new();
+  for (key, path) in theme_files(Path::new(&sync.dir)) {
+    let source = match String::from_utf8(std::fs::read(&path).unwrap()) {
+      Ok(value) => AssetSource::Value(value),
+      Err(err) => AssetSource::Attachment(BASE64.encode(err.into_bytes())),
+    };
+    client.put_asset(sync.theme_id, &key, &source).unwrap();
+    println!("push: {}", key);
+    keys.insert(key);
+  }
+
+  if sync.delete {
+    for asset in client.list_assets(sync.theme_id).unwrap() {
+      if keys.contains(&asset.key) {
+        continue;
+      }
+      // Shopify refuses to delete some required assets, e.g. `layout/theme.liquid`.
+      match client.delete_asset(sync.theme_id, &asset.key) {
+        Ok(_) => println!("delete: {}", asset.key),
+        Err(err) => eprintln!("delete {}: {}", asset.key, err),
+      }
+    }
+  }
+}
+
+/// Lists the files under `root` with their asset keys, skipping hidden files.
+fn theme_files(root: &std::path::Path) -> Vec<(String, std::path::PathBuf)> {
+  use std::path::{Path, PathBuf};
+
+  fn walk(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+      let path = entry.unwrap().path();
+      if path.file_name().unwrap().to_string_lossy().starts_with('.') {
+        continue;
+      }
+      if path.is_dir() {
+        walk(&path, files);
+      } else {
+        files.push(path);
+      }
+    }
+  }
+
+  let mut files = vec![];
+  walk(root, &mut files);
+  files.sort();
+  files
+    .into_iter()
+    .map(|path| {
+      let key = path
+        .strip_prefix(root)
+        .unwrap()
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+      (key, path)
+    })
+    .collect()
+}
+
+/// The local path of an asset, or `None` if the key would escape `root` (`..`, absolute paths).
+fn theme_asset_path(root: &std::path::Path, key: &str) -> Option<std::path::PathBuf> {
+  use std::path::{Component, Path};
+
+  let key = Path::new(key);
+  let is_relative = key.components().next().is_some()
+    && key.components().all(|c| matches!(c, Component::Normal(_)));
+  if is_relative {
+    Some(root.join(key))
+  } else {
+    None
+  }
+}
@@ -0,0 +1,286 @@
+use crate::client::{Client, Method};
+use crate::pagination::{GetPage, Paginated};
+use crate::result::*;
+use crate::types::{DateTime, Utc};
+
+mod types;
+pub use self::types::*;
+
+request_query! {
+  pub struct GetAbandonedCheckoutListParams {
+    pub limit: Option<i64>,
+    pub since_id: Option<i64>,
+    pub status: Option<AbandonedCheckoutStatus>,
+    pub created_at_min: Option<DateTime<Utc>>,
+    pub created_at_max: Option<DateTime<Utc>>,
+    pub updated_at_min: Option<DateTime<Utc>>,
+    pub updated_at_max: Option<DateTime<Utc>>,
+  }
+}
+
+request_query! {
+  pub struct GetAbandonedCheckoutCountParams {
+    pub since_id: Option<i64>,
+    pub status: Option<AbandonedCheckoutStatus>,
+    pub created_at_min: Option<DateTime<Utc>>,
+    pub created_at_max: Option<DateTime<Utc>>,
+    pub updated_at_min: Option<DateTime<Utc>>,
+    pub updated_at_max: Option<DateTime<Utc>>,
+  }
+}
+
+pub trait AbandonedCheckoutApi {
+  fn list(
+    &self,
+    params: &GetAbandonedCheckoutListParams,
+  ) -> ShopifyResult<Paginated<Vec<AbandonedCheckout>>>;
+
+  fn list_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<AbandonedCheckout>>>;
+
+  fn count(&self, params: &GetAbandonedCheckoutCountParams) -> ShopifyResult<i64>;
+}
+
+impl AbandonedCheckoutApi for Client {
+  fn list(
+    &self,
+    params: &GetAbandonedCheckoutListParams,
+  ) -> ShopifyResult<Paginated<Vec<AbandonedCheckout>>> {
+    shopify_wrap! {
+      pub struct Res {
+        checkouts: Vec<AbandonedCheckout>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/checkouts.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<AbandonedCheckout>>> {
+    shopify_wrap! {
+      pub struct Res {
+        checkouts: Vec<AbandonedCheckout>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/checkouts.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn count(&self, params: &GetAbandonedCheckoutCountParams) -> ShopifyResult<i64> {
+    shopify_wrap! {
+      pub struct Res {
+        count: i64,
+      }
+    }
+
+    let res: Res = self.request_with_params(
+      Method::GET,
+      "/admin/api/2023-07/checkouts/count.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.into_inner())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_deserialize_abandoned_checkout() {
+    shopify_wrap! {
+      pub struct Res {
+        checkouts: Vec<AbandonedCheckout>,
+      }
+    }
+
+    let checkouts = serde_json::from_str::<Res>(
+      r##"
+    {
+      "checkouts": [
+        {
+          "id": 450789469,
+          "token": "2a1ace52255252df566af0faaedfbfa7",
+          "cart_token": "68778783ad298f1c80c3bafcddeea02f",
+          "email": "bob.norman@mail.example.com",
+          "gateway": null,
+          "buyer_accepts_marketing": false,
+          "created_at": "2012-10-12T07:05:27-04:00",
+          "updated_at": "2012-10-12T07:05:27-04:00",
+          "landing_site": null,
+          "note": null,
+          "note_attributes": [
+            { "name": "custom engraving", "value": "Happy Birthday" }
+          ],
+          "referring_site": null,
+          "shipping_lines": [
+            {
+              "code": "Free Shipping",
+              "price": "0.00",
+              "original_shop_price": "0.00",
+              "original_shop_markup": "0.00",
+              "source": "shopify",
+              "title": "Free Shipping",
+              "presentment_title": "Free Shipping",
+              "phone": null,
+              "tax_lines": [],
+              "custom_tax_lines": null,
+              "markup": "0.00",
+              "carrier_identifier": null,
+              "carrier_service_id": null,
+              "api_client_id": null,
+              "delivery_option_group": { "token": "26492b5e5d3d3b5f7b5e6bb4d9a0c8a2", "type": "one_time_purchase" },
+              "delivery_expectation_range": null,
+              "delivery_expectation_type": null,
+              "id": null,
+              "requested_fulfillment_service_id": null,
+              "delivery_category": null,
+              "validation_context": null,
+              "applied_discounts": []
+            }
+          ],
+          "taxes_included": false,
+          "total_weight": 0,
+          "currency": "USD",
+          "completed_at": null,
+          "closed_at": null,
+          "user_id": null,
+          "location_id": null,
+          "source_identifier": null,
+          "source_url": null,
+          "device_id": null,
+          "phone": null,
+          "customer_locale": null,
+          "line_items": [
+            {
+              "applied_discounts": [],
+              "discount_allocations": [],
+              "key": "0f4b5e8e12d3b4e0b1c4e1b2e1f3b5a7",
+              "destination_location_id": 1059755717,
+              "fulfillment_service": "manual",
+              "gift_card": false,
+              "grams": 200,
+              "origin_location_id": 1059755718,
+              "presentment_title": "IPod Nano - 8GB",
+              "presentment_variant_title": "Pink",
+              "product_id": 632910392,
+              "properties": null,
+              "quantity": 1,
+              "requires_shipping": true,
+              "sku": "IPOD2008PINK",
+              "tax_lines": [],
+              "taxable": true,
+              "title": "IPod Nano - 8GB",
+              "variant_id": 808950810,
+              "variant_title": "Pink",
+              "variant_price": "199.00",
+              "vendor": "Apple",
+              "user_id": null,
+              "unit_price_measurement": null,
+              "rank": null,
+              "compare_at_price": null,
+              "line_price": "199.00",
+              "price": "199.00"
+            }
+          ],
+          "name": "#450789469",
+          "source": null,
+          "abandoned_checkout_url": "https://checkout.local/548380009/checkouts/2a1ace52255252df566af0faaedfbfa7/recover?key=2a1ace52255252df",
+          "discount_codes": [],
+          "tax_lines": [],
+          "source_name": "web",
+          "presentment_currency": "USD",
+          "buyer_accepts_sms_marketing": false,
+          "sms_marketing_phone": null,
+          "total_discounts": "0.00",
+          "total_line_items_price": "199.00",
+          "total_price": "199.00",
+          "total_tax": "0.00",
+          "subtotal_price": "199.00",
+          "total_duties": null,
+          "billing_address": {
+            "first_name": "Bob",
+            "address1": "Chestnut Street 92",
+            "phone": "+1(502)-459-2181",
+            "city": "Louisville",
+            "zip": "40202",
+            "province": "Kentucky",
+            "country": "United States",
+            "last_name": "Norman",
+            "address2": "",
+            "company": null,
+            "latitude": null,
+            "longitude": null,
+            "name": "Bob Norman",
+            "country_code": "US",
+            "province_code": "KY"
+          },
+          "shipping_address": null,
+          "customer": {
+            "id": 207119551,
+            "email": "bob.norman@mail.example.com",
+            "accepts_marketing": false,
+            "created_at": "2023-07-11T18:33:22-04:00",
+            "updated_at": "2023-07-11T18:33:22-04:00",
+            "first_name": "Bob",
+            "last_name": "Norman",
+            "orders_count": 1,
+            "state": "disabled",
+            "total_spent": "199.65",
+            "last_order_id": 450789469,
+            "note": null,
+            "verified_email": true,
+            "multipass_identifier": null,
+            "tax_exempt": false,
+            "tags": "Léon, Noël",
+            "last_order_name": "#1001",
+            "currency": "USD",
+            "phone": "+16136120707",
+            "default_address": null
+          }
+        }
+      ]
+    }
+    "##,
+    )
+    .unwrap()
+    .into_inner();
+    let checkout = &checkouts[0];
+    assert!(checkout
+      .abandoned_checkout_url
+      .ends_with("/recover?key=2a1ace52255252df"));
+    assert_eq!(checkout.line_items[0].sku.as_deref(), Some("IPOD2008PINK"));
+    assert_eq!(checkout.customer.as_ref().map(|c| c.id), Some(207119551));
+    assert_eq!(
+      checkout
+        .billing_address
+        .as_ref()
+        .and_then(|a| a.province_code.as_deref()),
+      Some("KY")
+    );
+  }
+
+  #[test]
+  #[ignore]
+  fn test_list_abandoned_checkouts() {
+    let client = crate::client::get_test_client();
+    let page = client
+      .list(&GetAbandonedCheckoutListParams {
+        status: Some(AbandonedCheckoutStatus::Open),
+        ..Default::default()
+      })
+      .unwrap();
+    println!("{:#?}", page);
+  }
+}
@@ -0,0 +1,102 @@
+use crate::client::AsQueryValue;
+use crate::order::{Address, Customer, DiscountCode, Property, TaxLines};
+use crate::types::{DateTime, Utc, Value};
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AbandonedCheckoutStatus {
+  /// Checkouts that haven't been recovered yet.
+  Open,
+  /// Checkouts that were completed or closed.
+  Closed,
+}
+
+impl AsQueryValue for AbandonedCheckoutStatus {
+  fn as_query_value(&self) -> String {
+    match *self {
+      AbandonedCheckoutStatus::Open => "open",
+      AbandonedCheckoutStatus::Closed => "closed",
+    }
+    .to_owned()
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AbandonedCheckoutLineItem {
+  /// Identifies the line item within the checkout.
+  pub key: String,
+  pub product_id: Option<i64>,
+  pub variant_id: Option<i64>,
+  pub title: String,
+  pub variant_title: Option<String>,
+  pub sku: Option<String>,
+  pub vendor: Option<String>,
+  pub quantity: i64,
+  pub price: String,
+  /// `price` multiplied by `quantity`.
+  pub line_price: Option<String>,
+  pub compare_at_price: Option<String>,
+  pub grams: Option<i64>,
+  pub requires_shipping: bool,
+  pub taxable: bool,
+  pub gift_card: bool,
+  pub fulfillment_service: Option<String>,
+  /// Line item properties, either as an object or a list of name/value pairs.
+  pub properties: Option<Value>,
+  #[serde(default)]
+  pub tax_lines: Vec<TaxLines>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AbandonedCheckoutShippingLine {
+  pub code: Option<String>,
+  pub title: String,
+  pub price: String,
+  pub source: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AbandonedCheckout {
+  pub id: i64,
+  pub token: String,
+  pub cart_token: Option<String>,
+  /// The name of the checkout, e.g. `#1001`.
+  pub name: Option<String>,
+  pub email: Option<String>,
+  pub phone: Option<String>,
+  /// The link that brings the customer back to their checkout.
+  pub abandoned_checkout_url: String,
+  pub buyer_accepts_marketing: Option<bool>,
+  pub currency: String,
+  pub presentment_currency: Option<String>,
+  pub customer_locale: Option<String>,
+  pub subtotal_price: String,
+  pub total_discounts: String,
+  pub total_line_items_price: String,
+  pub total_price: String,
+  pub total_tax: String,
+  pub total_weight: Option<i64>,
+  pub taxes_included: bool,
+  pub note: Option<String>,
+  #[serde(default)]
+  pub note_attributes: Vec<Property>,
+  pub landing_site: Option<String>,
+  pub referring_site: Option<String>,
+  pub source_name: Option<String>,
+  pub location_id: Option<i64>,
+  pub user_id: Option<i64>,
+  pub line_items: Vec<AbandonedCheckoutLineItem>,
+  #[serde(default)]
+  pub shipping_lines: Vec<AbandonedCheckoutShippingLine>,
+  #[serde(default)]
+  pub tax_lines: Vec<TaxLines>,
+  #[serde(default)]
+  pub discount_codes: Vec<DiscountCode>,
+  pub billing_address: Option<Address>,
+  pub shipping_address: Option<Address>,
+  pub customer: Option<Customer>,
+  pub created_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+  pub completed_at: Option<DateTime<Utc>>,
+  pub closed_at: Option<DateTime<Utc>>,
+}
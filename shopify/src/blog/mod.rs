@@ -0,0 +1,312 @@
+use crate::client::{Client, Method};
+use crate::pagination::{GetPage, Paginated};
+use crate::result::*;
+use crate::types::{DateTime, Utc};
+use serde::Serialize;
+
+mod types;
+pub use self::types::*;
+
+request_query! {
+  pub struct GetBlogListParams {
+    pub limit: Option<i64>,
+    pub since_id: Option<i64>,
+    pub handle: Option<String>,
+    pub fields: Option<Vec<String>>,
+  }
+}
+
+request_query! {
+  pub struct GetArticleListParams {
+    pub limit: Option<i64>,
+    pub since_id: Option<i64>,
+    pub author: Option<String>,
+    pub handle: Option<String>,
+    pub tag: Option<String>,
+    pub created_at_min: Option<DateTime<Utc>>,
+    pub created_at_max: Option<DateTime<Utc>>,
+    pub updated_at_min: Option<DateTime<Utc>>,
+    pub updated_at_max: Option<DateTime<Utc>>,
+    pub published_at_min: Option<DateTime<Utc>>,
+    pub published_at_max: Option<DateTime<Utc>>,
+    pub published_status: Option<String>,
+    pub fields: Option<Vec<String>>,
+  }
+}
+
+pub trait BlogApi {
+  fn list_blogs(&self, params: &GetBlogListParams) -> ShopifyResult<Paginated<Vec<Blog>>>;
+
+  fn list_blogs_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<Blog>>>;
+
+  fn get_blog(&self, id: i64) -> ShopifyResult<Blog>;
+
+  fn create_blog(&self, blog: &NewBlog) -> ShopifyResult<Blog>;
+
+  fn update_blog<V: Serialize>(&self, id: i64, value: V) -> ShopifyResult<Blog>;
+
+  /// Deletes the blog and all of its articles.
+  fn delete_blog(&self, id: i64) -> ShopifyResult<()>;
+}
+
+impl BlogApi for Client {
+  fn list_blogs(&self, params: &GetBlogListParams) -> ShopifyResult<Paginated<Vec<Blog>>> {
+    shopify_wrap! {
+      pub struct Res {
+        blogs: Vec<Blog>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/blogs.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_blogs_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<Blog>>> {
+    shopify_wrap! {
+      pub struct Res {
+        blogs: Vec<Blog>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/blogs.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn get_blog(&self, id: i64) -> ShopifyResult<Blog> {
+    shopify_wrap! {
+      pub struct Res {
+        blog: Blog,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/blogs/{}.json", id);
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn create_blog(&self, blog: &NewBlog) -> ShopifyResult<Blog> {
+    shopify_wrap! {
+      pub struct Res {
+        blog: Blog,
+      }
+    }
+
+    let res: Res = self.request(Method::POST, "/admin/api/2023-07/blogs.json", move |b| {
+      b.json(&json!({ "blog": blog }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn update_blog<V: Serialize>(&self, id: i64, value: V) -> ShopifyResult<Blog> {
+    shopify_wrap! {
+      pub struct Res {
+        blog: Blog,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/blogs/{}.json", id);
+    let res: Res = self.request(Method::PUT, &path, move |b| {
+      b.json(&json!({ "blog": value }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn delete_blog(&self, id: i64) -> ShopifyResult<()> {
+    let path = format!("/admin/api/2023-07/blogs/{}.json", id);
+    self.request_no_content(Method::DELETE, &path, std::convert::identity)
+  }
+}
+
+pub trait ArticleApi {
+  fn list_articles(
+    &self,
+    blog_id: i64,
+    params: &GetArticleListParams,
+  ) -> ShopifyResult<Paginated<Vec<Article>>>;
+
+  fn list_articles_page(
+    &self,
+    blog_id: i64,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<Article>>>;
+
+  fn get_article(&self, blog_id: i64, id: i64) -> ShopifyResult<Article>;
+
+  fn create_article(&self, blog_id: i64, article: &NewArticle) -> ShopifyResult<Article>;
+
+  fn update_article<V: Serialize>(&self, blog_id: i64, id: i64, value: V)
+    -> ShopifyResult<Article>;
+
+  fn delete_article(&self, blog_id: i64, id: i64) -> ShopifyResult<()>;
+}
+
+impl ArticleApi for Client {
+  fn list_articles(
+    &self,
+    blog_id: i64,
+    params: &GetArticleListParams,
+  ) -> ShopifyResult<Paginated<Vec<Article>>> {
+    shopify_wrap! {
+      pub struct Res {
+        articles: Vec<Article>,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/blogs/{}/articles.json", blog_id);
+    let res: Paginated<Res> =
+      self.request_with_params_paginated(Method::GET, &path, params, std::convert::identity)?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_articles_page(
+    &self,
+    blog_id: i64,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<Article>>> {
+    shopify_wrap! {
+      pub struct Res {
+        articles: Vec<Article>,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/blogs/{}/articles.json", blog_id);
+    let res: Paginated<Res> =
+      self.request_with_params_paginated(Method::GET, &path, params, std::convert::identity)?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn get_article(&self, blog_id: i64, id: i64) -> ShopifyResult<Article> {
+    shopify_wrap! {
+      pub struct Res {
+        article: Article,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/blogs/{}/articles/{}.json", blog_id, id);
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn create_article(&self, blog_id: i64, article: &NewArticle) -> ShopifyResult<Article> {
+    shopify_wrap! {
+      pub struct Res {
+        article: Article,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/blogs/{}/articles.json", blog_id);
+    let res: Res = self.request(Method::POST, &path, move |b| {
+      b.json(&json!({ "article": article }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn update_article<V: Serialize>(
+    &self,
+    blog_id: i64,
+    id: i64,
+    value: V,
+  ) -> ShopifyResult<Article> {
+    shopify_wrap! {
+      pub struct Res {
+        article: Article,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/blogs/{}/articles/{}.json", blog_id, id);
+    let res: Res = self.request(Method::PUT, &path, move |b| {
+      b.json(&json!({ "article": value }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn delete_article(&self, blog_id: i64, id: i64) -> ShopifyResult<()> {
+    let path = format!("/admin/api/2023-07/blogs/{}/articles/{}.json", blog_id, id);
+    self.request_no_content(Method::DELETE, &path, std::convert::identity)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_deserialize_article() {
+    shopify_wrap! {
+      pub struct Res {
+        article: Article,
+      }
+    }
+
+    let article = serde_json::from_str::<Res>(
+      r##"
+    {
+      "article": {
+        "id": 134645308,
+        "title": "get on the train now",
+        "created_at": "2008-07-31T20:00:00-04:00",
+        "body_html": "<p>Do <em>you</em> have an <strong>IPod</strong> yet?</p>",
+        "blog_id": 241253187,
+        "author": "Dennis",
+        "user_id": null,
+        "published_at": "2008-07-31T20:00:00-04:00",
+        "updated_at": "2008-07-31T20:00:00-04:00",
+        "summary_html": null,
+        "template_suffix": null,
+        "handle": "get-on-the-train-now",
+        "tags": "Mystery",
+        "admin_graphql_api_id": "gid://shopify/OnlineStoreArticle/134645308",
+        "image": {
+          "created_at": "2023-07-11T14:49:39-04:00",
+          "alt": "iPod Nano - 8gb",
+          "width": 123,
+          "height": 456,
+          "src": "https://cdn.shopify.com/s/files/1/0005/4838/0009/articles/ipod-nano.png?v=1689101379"
+        }
+      }
+    }
+    "##,
+    )
+    .unwrap()
+    .into_inner();
+    assert_eq!(article.blog_id, 241253187);
+    assert_eq!(
+      article.image.unwrap().alt.as_deref(),
+      Some("iPod Nano - 8gb")
+    );
+  }
+
+  #[test]
+  fn test_serialize_new_blog() {
+    let blog = NewBlog {
+      title: "News".to_owned(),
+      commentable: Some(BlogCommentable::Moderate),
+      ..Default::default()
+    };
+    assert_eq!(
+      serde_json::to_value(blog).unwrap(),
+      serde_json::json!({
+        "title": "News",
+        "commentable": "moderate",
+      })
+    );
+  }
+
+  #[test]
+  #[ignore]
+  fn test_list_blogs() {
+    let client = crate::client::get_test_client();
+    let page = client.list_blogs(&Default::default()).unwrap();
+    println!("{:#?}", page);
+  }
+}
@@ -0,0 +1,101 @@
+use crate::types::{DateTime, Utc};
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BlogCommentable {
+  /// Comments are disabled.
+  No,
+  /// Comments are held for review before they are published.
+  Moderate,
+  /// Comments are published without review.
+  Yes,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Blog {
+  pub id: i64,
+  pub title: String,
+  pub handle: String,
+  pub commentable: Option<BlogCommentable>,
+  pub feedburner: Option<String>,
+  pub feedburner_location: Option<String>,
+  pub template_suffix: Option<String>,
+  /// Comma-separated tags of all articles in the blog.
+  pub tags: Option<String>,
+  pub created_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct NewBlog {
+  pub title: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub handle: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub commentable: Option<BlogCommentable>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub template_suffix: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ArticleImage {
+  pub src: String,
+  pub alt: Option<String>,
+  pub width: Option<i64>,
+  pub height: Option<i64>,
+  pub created_at: Option<DateTime<Utc>>,
+}
+
+/// Either `src` or a base64 encoded `attachment` is required.
+#[derive(Debug, Serialize, Default)]
+pub struct NewArticleImage {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub src: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub attachment: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub alt: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Article {
+  pub id: i64,
+  pub blog_id: i64,
+  pub title: String,
+  pub handle: String,
+  pub author: Option<String>,
+  pub user_id: Option<i64>,
+  pub body_html: Option<String>,
+  pub summary_html: Option<String>,
+  /// Comma-separated tags.
+  pub tags: Option<String>,
+  pub template_suffix: Option<String>,
+  pub image: Option<ArticleImage>,
+  /// `None` if the article is hidden.
+  pub published_at: Option<DateTime<Utc>>,
+  pub created_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct NewArticle {
+  pub title: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub author: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub handle: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub body_html: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub summary_html: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tags: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub template_suffix: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub image: Option<NewArticleImage>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub published: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub published_at: Option<DateTime<Utc>>,
+}
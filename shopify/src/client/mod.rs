@@ -1,10 +1,12 @@
 use crate::pagination::Paginated;
 use crate::result::*;
 use crate::types::{DateTime, Utc};
+use chrono::NaiveDate;
 use reqwest::blocking::Response;
 pub use reqwest::Method;
 use reqwest::{blocking::Client as HttpClient, blocking::RequestBuilder, StatusCode, Url};
 use serde::Deserialize;
+use serde_json::Value;
 
 mod types;
 pub use self::types::*;
@@ -59,7 +61,9 @@ macro_rules! impl_into_query_value {
 }
 
 impl_into_query_value!(i64);
+impl_into_query_value!(bool);
 impl_into_query_value!(String);
+impl_into_query_value!(NaiveDate);
 impl_into_query_value!(DateTime<Utc>, |date: &DateTime<Utc>| date.to_rfc3339());
 
 impl<T> AsQueryValue for Vec<T>
@@ -84,8 +88,6 @@ macro_rules! request_query {
       $(,)*
     }
   ) => (
-    use $crate::client::{ShopifyRequestQuery, AsQueryValue};
-
     #[derive(Debug, Default)]
     pub struct $t {
       $(
@@ -93,8 +95,10 @@ macro_rules! request_query {
       ),*
     }
 
-    impl ShopifyRequestQuery for $t {
+    impl $crate::client::ShopifyRequestQuery for $t {
       fn as_query_pairs(&self) -> Vec<(String, String)> {
+        #[allow(unused_imports)]
+        use $crate::client::AsQueryValue;
         let mut pairs = vec![];
         $(
           request_query!(PUSH_FIELD self, pairs, $key, $field_t);
@@ -165,22 +169,7 @@ impl Client {
 
     b = bf(b);
 
-    let res = b.send()?;
-    let status = res.status();
-    if !status.is_success() {
-      if status == StatusCode::NOT_FOUND {
-        return Err(ShopifyError::NotFound);
-      }
-
-      let body = res.text()?;
-
-      return Err(ShopifyError::Request {
-        path: path.to_owned(),
-        status,
-        body,
-      });
-    }
-
+    let res = check_response(path, b.send()?)?;
     res.json().map_err(Into::into)
   }
 
@@ -203,22 +192,7 @@ impl Client {
 
     b = bf(b);
 
-    let res = b.send()?;
-    let status = res.status();
-
-    if !status.is_success() {
-      if status == StatusCode::NOT_FOUND {
-        return Err(ShopifyError::NotFound);
-      }
-
-      let body = res.text()?;
-      return Err(ShopifyError::Request {
-        path: path.to_owned(),
-        status,
-        body,
-      });
-    }
-
+    let res = check_response(path, b.send()?)?;
     Paginated::from_res(res)
   }
 
@@ -255,6 +229,50 @@ impl Client {
 
     b.send().map_err(Into::into)
   }
+
+  /// Sends a request to an endpoint that does not return a body (e.g. `204 No Content`).
+  pub fn request_no_content<F>(&self, method: Method, path: &str, bf: F) -> ShopifyResult<()>
+  where
+    F: FnOnce(RequestBuilder) -> RequestBuilder,
+  {
+    check_response(path, self.request_raw(method, path, bf)?)?;
+    Ok(())
+  }
+
+  /// Sends a query or mutation to the GraphQL Admin API and returns its `data`.
+  pub fn graphql<T>(&self, query: &str, variables: Value) -> ShopifyResult<T>
+  where
+    T: for<'de> Deserialize<'de>,
+  {
+    let res: GraphQLResponse<T> =
+      self.request(Method::POST, "/admin/api/2023-07/graphql.json", |b| {
+        b.json(&json!({
+          "query": query,
+          "variables": variables,
+        }))
+      })?;
+    if !res.errors.is_empty() {
+      return Err(ShopifyError::GraphQL(res.errors));
+    }
+    res.data.ok_or(ShopifyError::InvalidResponse)
+  }
+}
+
+pub(crate) fn check_response(path: &str, res: Response) -> ShopifyResult<Response> {
+  let status = res.status();
+  if !status.is_success() {
+    if status == StatusCode::NOT_FOUND {
+      return Err(ShopifyError::NotFound);
+    }
+
+    let body = res.text()?;
+    return Err(ShopifyError::Request {
+      path: path.to_owned(),
+      status,
+      body,
+    });
+  }
+  Ok(res)
 }
 
 #[cfg(test)]
@@ -35,3 +35,35 @@ where
     vec![(self.0.as_ref().to_owned(), self.1.as_ref().to_owned())]
   }
 }
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphQLResponse<T> {
+  pub data: Option<T>,
+  #[serde(default)]
+  pub errors: Vec<GraphQLError>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GraphQLError {
+  pub message: String,
+  pub extensions: Option<serde_json::Value>,
+}
+
+impl GraphQLError {
+  /// The `extensions.code` of the error, e.g. `THROTTLED`.
+  pub fn code(&self) -> Option<&str> {
+    self
+      .extensions
+      .as_ref()
+      .and_then(|v| v.get("code"))
+      .and_then(|v| v.as_str())
+  }
+}
+
+/// A validation error returned by a GraphQL mutation.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserError {
+  pub field: Option<Vec<String>>,
+  pub message: String,
+  pub code: Option<String>,
+}
@@ -0,0 +1,545 @@
+use crate::client::{Client, Method};
+use crate::pagination::{GetPage, Paginated};
+use crate::product::Product;
+use crate::result::*;
+use crate::types::{DateTime, Utc};
+use serde::Serialize;
+
+mod types;
+pub use self::types::*;
+
+request_query! {
+  pub struct GetCollectionListParams {
+    pub ids: Option<Vec<i64>>,
+    pub limit: Option<i64>,
+    pub since_id: Option<i64>,
+    pub title: Option<String>,
+    pub handle: Option<String>,
+    pub product_id: Option<i64>,
+    pub updated_at_min: Option<DateTime<Utc>>,
+    pub updated_at_max: Option<DateTime<Utc>>,
+    pub published_at_min: Option<DateTime<Utc>>,
+    pub published_at_max: Option<DateTime<Utc>>,
+    pub published_status: Option<String>,
+    pub fields: Option<Vec<String>>,
+  }
+}
+
+request_query! {
+  pub struct GetCollectListParams {
+    pub limit: Option<i64>,
+    pub since_id: Option<i64>,
+    pub fields: Option<Vec<String>>,
+  }
+}
+
+request_query! {
+  pub struct GetCollectionProductListParams {
+    pub limit: Option<i64>,
+  }
+}
+
+pub trait CustomCollectionApi {
+  fn list_custom_collections(
+    &self,
+    params: &GetCollectionListParams,
+  ) -> ShopifyResult<Paginated<Vec<CustomCollection>>>;
+
+  fn list_custom_collections_page(
+    &self,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<CustomCollection>>>;
+
+  fn get_custom_collection(&self, id: i64) -> ShopifyResult<CustomCollection>;
+
+  fn create_custom_collection(
+    &self,
+    collection: &NewCustomCollection,
+  ) -> ShopifyResult<CustomCollection>;
+
+  fn update_custom_collection<V: Serialize>(
+    &self,
+    id: i64,
+    value: V,
+  ) -> ShopifyResult<CustomCollection>;
+
+  fn delete_custom_collection(&self, id: i64) -> ShopifyResult<()>;
+}
+
+impl CustomCollectionApi for Client {
+  fn list_custom_collections(
+    &self,
+    params: &GetCollectionListParams,
+  ) -> ShopifyResult<Paginated<Vec<CustomCollection>>> {
+    shopify_wrap! {
+      pub struct Res {
+        custom_collections: Vec<CustomCollection>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/custom_collections.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_custom_collections_page(
+    &self,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<CustomCollection>>> {
+    shopify_wrap! {
+      pub struct Res {
+        custom_collections: Vec<CustomCollection>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/custom_collections.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn get_custom_collection(&self, id: i64) -> ShopifyResult<CustomCollection> {
+    shopify_wrap! {
+      pub struct Res {
+        custom_collection: CustomCollection,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/custom_collections/{}.json", id);
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn create_custom_collection(
+    &self,
+    collection: &NewCustomCollection,
+  ) -> ShopifyResult<CustomCollection> {
+    shopify_wrap! {
+      pub struct Res {
+        custom_collection: CustomCollection,
+      }
+    }
+
+    let res: Res = self.request(
+      Method::POST,
+      "/admin/api/2023-07/custom_collections.json",
+      move |b| b.json(&json!({ "custom_collection": collection })),
+    )?;
+    Ok(res.into_inner())
+  }
+
+  fn update_custom_collection<V: Serialize>(
+    &self,
+    id: i64,
+    value: V,
+  ) -> ShopifyResult<CustomCollection> {
+    shopify_wrap! {
+      pub struct Res {
+        custom_collection: CustomCollection,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/custom_collections/{}.json", id);
+    let res: Res = self.request(Method::PUT, &path, move |b| {
+      b.json(&json!({ "custom_collection": value }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn delete_custom_collection(&self, id: i64) -> ShopifyResult<()> {
+    let path = format!("/admin/api/2023-07/custom_collections/{}.json", id);
+    self.request_no_content(Method::DELETE, &path, std::convert::identity)
+  }
+}
+
+pub trait SmartCollectionApi {
+  fn list_smart_collections(
+    &self,
+    params: &GetCollectionListParams,
+  ) -> ShopifyResult<Paginated<Vec<SmartCollection>>>;
+
+  fn list_smart_collections_page(
+    &self,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<SmartCollection>>>;
+
+  fn get_smart_collection(&self, id: i64) -> ShopifyResult<SmartCollection>;
+
+  fn create_smart_collection(
+    &self,
+    collection: &NewSmartCollection,
+  ) -> ShopifyResult<SmartCollection>;
+
+  fn update_smart_collection<V: Serialize>(
+    &self,
+    id: i64,
+    value: V,
+  ) -> ShopifyResult<SmartCollection>;
+
+  fn delete_smart_collection(&self, id: i64) -> ShopifyResult<()>;
+}
+
+impl SmartCollectionApi for Client {
+  fn list_smart_collections(
+    &self,
+    params: &GetCollectionListParams,
+  ) -> ShopifyResult<Paginated<Vec<SmartCollection>>> {
+    shopify_wrap! {
+      pub struct Res {
+        smart_collections: Vec<SmartCollection>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/smart_collections.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_smart_collections_page(
+    &self,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<SmartCollection>>> {
+    shopify_wrap! {
+      pub struct Res {
+        smart_collections: Vec<SmartCollection>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/smart_collections.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn get_smart_collection(&self, id: i64) -> ShopifyResult<SmartCollection> {
+    shopify_wrap! {
+      pub struct Res {
+        smart_collection: SmartCollection,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/smart_collections/{}.json", id);
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn create_smart_collection(
+    &self,
+    collection: &NewSmartCollection,
+  ) -> ShopifyResult<SmartCollection> {
+    shopify_wrap! {
+      pub struct Res {
+        smart_collection: SmartCollection,
+      }
+    }
+
+    let res: Res = self.request(
+      Method::POST,
+      "/admin/api/2023-07/smart_collections.json",
+      move |b| b.json(&json!({ "smart_collection": collection })),
+    )?;
+    Ok(res.into_inner())
+  }
+
+  fn update_smart_collection<V: Serialize>(
+    &self,
+    id: i64,
+    value: V,
+  ) -> ShopifyResult<SmartCollection> {
+    shopify_wrap! {
+      pub struct Res {
+        smart_collection: SmartCollection,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/smart_collections/{}.json", id);
+    let res: Res = self.request(Method::PUT, &path, move |b| {
+      b.json(&json!({ "smart_collection": value }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn delete_smart_collection(&self, id: i64) -> ShopifyResult<()> {
+    let path = format!("/admin/api/2023-07/smart_collections/{}.json", id);
+    self.request_no_content(Method::DELETE, &path, std::convert::identity)
+  }
+}
+
+pub trait CollectApi {
+  fn list_collects(&self, params: &GetCollectListParams) -> ShopifyResult<Paginated<Vec<Collect>>>;
+
+  fn list_collects_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<Collect>>>;
+
+  fn get_collect(&self, id: i64) -> ShopifyResult<Collect>;
+
+  /// Adds a product to a custom collection.
+  fn create_collect(&self, collection_id: i64, product_id: i64) -> ShopifyResult<Collect>;
+
+  /// Removes a product from a custom collection.
+  fn delete_collect(&self, id: i64) -> ShopifyResult<()>;
+}
+
+impl CollectApi for Client {
+  fn list_collects(&self, params: &GetCollectListParams) -> ShopifyResult<Paginated<Vec<Collect>>> {
+    shopify_wrap! {
+      pub struct Res {
+        collects: Vec<Collect>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/collects.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_collects_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<Collect>>> {
+    shopify_wrap! {
+      pub struct Res {
+        collects: Vec<Collect>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/collects.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn get_collect(&self, id: i64) -> ShopifyResult<Collect> {
+    shopify_wrap! {
+      pub struct Res {
+        collect: Collect,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/collects/{}.json", id);
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn create_collect(&self, collection_id: i64, product_id: i64) -> ShopifyResult<Collect> {
+    shopify_wrap! {
+      pub struct Res {
+        collect: Collect,
+      }
+    }
+
+    let res: Res = self.request(Method::POST, "/admin/api/2023-07/collects.json", |b| {
+      b.json(&json!({
+        "collect": {
+          "collection_id": collection_id,
+          "product_id": product_id,
+        }
+      }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn delete_collect(&self, id: i64) -> ShopifyResult<()> {
+    let path = format!("/admin/api/2023-07/collects/{}.json", id);
+    self.request_no_content(Method::DELETE, &path, std::convert::identity)
+  }
+}
+
+pub trait CollectionApi {
+  /// Lists the products of a custom or smart collection, in the collection's sort order.
+  ///
+  /// The returned products don't include their variants.
+  fn list_collection_products(
+    &self,
+    collection_id: i64,
+    params: &GetCollectionProductListParams,
+  ) -> ShopifyResult<Paginated<Vec<Product>>>;
+
+  fn list_collection_products_page(
+    &self,
+    collection_id: i64,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<Product>>>;
+}
+
+impl CollectionApi for Client {
+  fn list_collection_products(
+    &self,
+    collection_id: i64,
+    params: &GetCollectionProductListParams,
+  ) -> ShopifyResult<Paginated<Vec<Product>>> {
+    shopify_wrap! {
+      pub struct Res {
+        products: Vec<Product>,
+      }
+    }
+
+    let path = format!(
+      "/admin/api/2023-07/collections/{}/products.json",
+      collection_id
+    );
+    let res: Paginated<Res> =
+      self.request_with_params_paginated(Method::GET, &path, params, std::convert::identity)?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_collection_products_page(
+    &self,
+    collection_id: i64,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<Product>>> {
+    shopify_wrap! {
+      pub struct Res {
+        products: Vec<Product>,
+      }
+    }
+
+    let path = format!(
+      "/admin/api/2023-07/collections/{}/products.json",
+      collection_id
+    );
+    let res: Paginated<Res> =
+      self.request_with_params_paginated(Method::GET, &path, params, std::convert::identity)?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_deserialize_smart_collection() {
+    shopify_wrap! {
+      pub struct Res {
+        smart_collection: SmartCollection,
+      }
+    }
+
+    let collection = serde_json::from_str::<Res>(
+      r##"
+    {
+      "smart_collection": {
+        "id": 1063001322,
+        "handle": "ipods-1",
+        "title": "IPods",
+        "updated_at": "2023-07-11T18:33:22-04:00",
+        "body_html": null,
+        "published_at": "2023-07-11T18:33:22-04:00",
+        "sort_order": "best-selling",
+        "template_suffix": null,
+        "disjunctive": false,
+        "rules": [
+          {
+            "column": "title",
+            "relation": "starts_with",
+            "condition": "iPod"
+          },
+          {
+            "column": "variant_price",
+            "relation": "greater_than",
+            "condition": "100"
+          }
+        ],
+        "published_scope": "web",
+        "admin_graphql_api_id": "gid://shopify/Collection/1063001322",
+        "image": {
+          "created_at": "2023-07-11T18:33:22-04:00",
+          "alt": null,
+          "width": 123,
+          "height": 456,
+          "src": "https://cdn.shopify.com/s/files/1/0005/4838/0009/collections/ipod.jpg"
+        }
+      }
+    }
+    "##,
+    )
+    .unwrap()
+    .into_inner();
+    assert_eq!(
+      collection.sort_order,
+      Some(CollectionSortOrder::BestSelling)
+    );
+    assert_eq!(collection.published_scope, Some(PublishedScope::Web));
+    assert_eq!(
+      collection.rules,
+      vec![
+        SmartCollectionRule::new(
+          SmartCollectionRuleColumn::Title,
+          SmartCollectionRuleRelation::StartsWith,
+          "iPod"
+        ),
+        SmartCollectionRule::new(
+          SmartCollectionRuleColumn::VariantPrice,
+          SmartCollectionRuleRelation::GreaterThan,
+          "100"
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_serialize_new_smart_collection() {
+    let collection = NewSmartCollection {
+      title: "Sale".to_owned(),
+      rules: vec![
+        SmartCollectionRule::new(
+          SmartCollectionRuleColumn::Tag,
+          SmartCollectionRuleRelation::Equals,
+          "sale",
+        ),
+        SmartCollectionRule::new(
+          SmartCollectionRuleColumn::IsPriceReduced,
+          SmartCollectionRuleRelation::Equals,
+          "true",
+        ),
+      ],
+      disjunctive: true,
+      sort_order: Some(CollectionSortOrder::PriceAsc),
+      ..Default::default()
+    };
+    assert_eq!(
+      serde_json::to_value(collection).unwrap(),
+      serde_json::json!({
+        "title": "Sale",
+        "rules": [
+          { "column": "tag", "relation": "equals", "condition": "sale" },
+          { "column": "is_price_reduced", "relation": "equals", "condition": "true" }
+        ],
+        "disjunctive": true,
+        "sort_order": "price-asc"
+      })
+    );
+  }
+
+  #[test]
+  #[ignore]
+  fn test_list_custom_collections() {
+    let client = crate::client::get_test_client();
+    let page = client.list_custom_collections(&Default::default()).unwrap();
+    println!("{:#?}", page);
+    if let Some(collection) = page.payload.first() {
+      let products = client
+        .list_collection_products(collection.id, &Default::default())
+        .unwrap();
+      println!("{:#?}", products);
+    }
+  }
+}
@@ -0,0 +1,179 @@
+use crate::types::{DateTime, Utc};
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum CollectionSortOrder {
+  AlphaAsc,
+  AlphaDesc,
+  BestSelling,
+  Created,
+  CreatedDesc,
+  Manual,
+  PriceAsc,
+  PriceDesc,
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PublishedScope {
+  /// Published to the online store channel only.
+  Web,
+  /// Published to the online store channel and the point of sale channel.
+  Global,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CollectionImage {
+  pub src: Option<String>,
+  pub alt: Option<String>,
+  pub width: Option<i64>,
+  pub height: Option<i64>,
+  pub created_at: Option<DateTime<Utc>>,
+}
+
+/// An image to upload, either from a URL or as a base64 encoded attachment.
+#[derive(Debug, Serialize, Default)]
+pub struct NewCollectionImage {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub src: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub attachment: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub alt: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomCollection {
+  pub id: i64,
+  pub handle: String,
+  pub title: String,
+  pub body_html: Option<String>,
+  pub sort_order: Option<CollectionSortOrder>,
+  pub template_suffix: Option<String>,
+  pub published_at: Option<DateTime<Utc>>,
+  pub published_scope: Option<PublishedScope>,
+  pub updated_at: Option<DateTime<Utc>>,
+  pub image: Option<CollectionImage>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct NewCustomCollection {
+  pub title: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub handle: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub body_html: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sort_order: Option<CollectionSortOrder>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub template_suffix: Option<String>,
+  /// Whether the collection is visible. Collections are published by default.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub published: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub published_scope: Option<PublishedScope>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub image: Option<NewCollectionImage>,
+}
+
+/// The product property a smart collection rule is applied to.
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SmartCollectionRuleColumn {
+  Title,
+  /// The product type.
+  Type,
+  Vendor,
+  Tag,
+  VariantTitle,
+  VariantPrice,
+  VariantCompareAtPrice,
+  VariantWeight,
+  VariantInventory,
+  /// Only valid with `relation: Equals` and a `condition` of `"true"` or `"false"`.
+  IsPriceReduced,
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SmartCollectionRuleRelation {
+  Equals,
+  NotEquals,
+  GreaterThan,
+  LessThan,
+  StartsWith,
+  EndsWith,
+  Contains,
+  NotContains,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SmartCollectionRule {
+  pub column: SmartCollectionRuleColumn,
+  pub relation: SmartCollectionRuleRelation,
+  pub condition: String,
+}
+
+impl SmartCollectionRule {
+  pub fn new(
+    column: SmartCollectionRuleColumn,
+    relation: SmartCollectionRuleRelation,
+    condition: &str,
+  ) -> Self {
+    SmartCollectionRule {
+      column,
+      relation,
+      condition: condition.to_owned(),
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SmartCollection {
+  pub id: i64,
+  pub handle: String,
+  pub title: String,
+  pub body_html: Option<String>,
+  pub sort_order: Option<CollectionSortOrder>,
+  pub template_suffix: Option<String>,
+  pub published_at: Option<DateTime<Utc>>,
+  pub published_scope: Option<PublishedScope>,
+  pub updated_at: Option<DateTime<Utc>>,
+  pub image: Option<CollectionImage>,
+  pub rules: Vec<SmartCollectionRule>,
+  /// Whether a product has to match any (`true`) or all (`false`) of the rules.
+  pub disjunctive: bool,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct NewSmartCollection {
+  pub title: String,
+  pub rules: Vec<SmartCollectionRule>,
+  pub disjunctive: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub handle: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub body_html: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sort_order: Option<CollectionSortOrder>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub template_suffix: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub published: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub published_scope: Option<PublishedScope>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub image: Option<NewCollectionImage>,
+}
+
+/// Links a product to a custom collection.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Collect {
+  pub id: i64,
+  pub collection_id: i64,
+  pub product_id: i64,
+  pub position: Option<i64>,
+  pub sort_value: Option<String>,
+  pub created_at: Option<DateTime<Utc>>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,320 @@
+use crate::client::{check_response, Client, Method};
+use crate::order::{Order, OrderApi};
+use crate::pagination::{GetPage, Paginated};
+use crate::result::*;
+use crate::types::{DateTime, Utc};
+use reqwest::StatusCode;
+use serde::Serialize;
+use std::time::Duration;
+
+mod types;
+pub use self::types::*;
+
+/// How many times an asynchronous completion is polled before giving up.
+const COMPLETION_POLL_ATTEMPTS: u32 = 30;
+
+request_query! {
+  pub struct GetDraftOrderListParams {
+    pub ids: Option<Vec<i64>>,
+    pub limit: Option<i64>,
+    pub since_id: Option<i64>,
+    pub status: Option<DraftOrderStatus>,
+    pub updated_at_min: Option<DateTime<Utc>>,
+    pub updated_at_max: Option<DateTime<Utc>>,
+    pub fields: Option<Vec<String>>,
+  }
+}
+
+pub trait DraftOrderApi {
+  fn list(&self, params: &GetDraftOrderListParams) -> ShopifyResult<Paginated<Vec<DraftOrder>>>;
+
+  fn list_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<DraftOrder>>>;
+
+  fn get(&self, id: i64) -> ShopifyResult<DraftOrder>;
+
+  fn create(&self, draft_order: &NewDraftOrder) -> ShopifyResult<DraftOrder>;
+
+  fn update<V: Serialize>(&self, id: i64, value: V) -> ShopifyResult<DraftOrder>;
+
+  fn delete(&self, id: i64) -> ShopifyResult<()>;
+
+  fn send_invoice(&self, id: i64, invoice: &DraftOrderInvoice) -> ShopifyResult<DraftOrderInvoice>;
+
+  /// Completes the draft order and returns the created order.
+  ///
+  /// If `payment_pending` is `true`, the order is marked as pending instead of paid.
+  ///
+  /// When Shopify completes the draft order asynchronously (`202 Accepted`), the draft order is
+  /// polled until it references the order. If that takes too long,
+  /// [`ShopifyError::DraftOrderCompletionPending`] is returned: the completion is still running
+  /// and must not be retried, poll the draft order with `get` instead.
+  fn complete(&self, id: i64, payment_pending: bool) -> ShopifyResult<Order>;
+}
+
+impl DraftOrderApi for Client {
+  fn list(&self, params: &GetDraftOrderListParams) -> ShopifyResult<Paginated<Vec<DraftOrder>>> {
+    shopify_wrap! {
+      pub struct Res {
+        draft_orders: Vec<DraftOrder>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/draft_orders.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<DraftOrder>>> {
+    shopify_wrap! {
+      pub struct Res {
+        draft_orders: Vec<DraftOrder>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/draft_orders.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn get(&self, id: i64) -> ShopifyResult<DraftOrder> {
+    shopify_wrap! {
+      pub struct Res {
+        draft_order: DraftOrder,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/draft_orders/{}.json", id);
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn create(&self, draft_order: &NewDraftOrder) -> ShopifyResult<DraftOrder> {
+    shopify_wrap! {
+      pub struct Res {
+        draft_order: DraftOrder,
+      }
+    }
+
+    let res: Res = self.request(
+      Method::POST,
+      "/admin/api/2023-07/draft_orders.json",
+      move |b| b.json(&json!({ "draft_order": draft_order })),
+    )?;
+    Ok(res.into_inner())
+  }
+
+  fn update<V: Serialize>(&self, id: i64, value: V) -> ShopifyResult<DraftOrder> {
+    shopify_wrap! {
+      pub struct Res {
+        draft_order: DraftOrder,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/draft_orders/{}.json", id);
+    let res: Res = self.request(Method::PUT, &path, move |b| {
+      b.json(&json!({ "draft_order": value }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn delete(&self, id: i64) -> ShopifyResult<()> {
+    let path = format!("/admin/api/2023-07/draft_orders/{}.json", id);
+    self.request_no_content(Method::DELETE, &path, std::convert::identity)
+  }
+
+  fn send_invoice(&self, id: i64, invoice: &DraftOrderInvoice) -> ShopifyResult<DraftOrderInvoice> {
+    shopify_wrap! {
+      pub struct Res {
+        draft_order_invoice: DraftOrderInvoice,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/draft_orders/{}/send_invoice.json", id);
+    let res: Res = self.request(Method::POST, &path, move |b| {
+      b.json(&json!({ "draft_order_invoice": invoice }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn complete(&self, id: i64, payment_pending: bool) -> ShopifyResult<Order> {
+    shopify_wrap! {
+      pub struct Res {
+        draft_order: DraftOrder,
+      }
+    }
+
+    let path = format!(
+      "/admin/api/2023-07/draft_orders/{}/complete.json?payment_pending={}",
+      id, payment_pending
+    );
+    let res = check_response(
+      &path,
+      self.request_raw(Method::PUT, &path, std::convert::identity)?,
+    )?;
+    let order_id = if res.status() == StatusCode::ACCEPTED {
+      let interval = res
+        .headers()
+        .get("retry-after")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<f64>().ok())
+        .map(Duration::from_secs_f64)
+        .unwrap_or_else(|| Duration::from_secs(1));
+      let mut order_id = None;
+      for _ in 0..COMPLETION_POLL_ATTEMPTS {
+        std::thread::sleep(interval);
+        order_id = DraftOrderApi::get(self, id)?.order_id;
+        if order_id.is_some() {
+          break;
+        }
+      }
+      order_id.ok_or(ShopifyError::DraftOrderCompletionPending { id })?
+    } else {
+      res
+        .json::<Res>()?
+        .into_inner()
+        .order_id
+        .ok_or(ShopifyError::InvalidResponse)?
+    };
+    OrderApi::get(self, order_id)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::order::NewLineItem;
+
+  #[test]
+  fn test_deserialize_draft_order() {
+    shopify_wrap! {
+      pub struct Res {
+        draft_order: DraftOrder,
+      }
+    }
+
+    let draft_order = serde_json::from_str::<Res>(
+      r##"
+    {
+      "draft_order": {
+        "id": 994118539,
+        "note": "rush order",
+        "email": "bob.norman@mail.example.com",
+        "taxes_included": false,
+        "currency": "USD",
+        "invoice_sent_at": null,
+        "created_at": "2023-07-11T18:36:29-04:00",
+        "updated_at": "2023-07-11T18:36:29-04:00",
+        "tax_exempt": false,
+        "completed_at": null,
+        "name": "#D2",
+        "status": "open",
+        "line_items": [
+          {
+            "id": 994118539,
+            "variant_id": 39072856,
+            "product_id": 632910392,
+            "title": "IPod Nano - 8gb",
+            "variant_title": "green",
+            "sku": "IPOD2008GREEN",
+            "vendor": null,
+            "quantity": 1,
+            "requires_shipping": false,
+            "taxable": true,
+            "gift_card": false,
+            "fulfillment_service": "manual",
+            "grams": 567,
+            "tax_lines": [],
+            "applied_discount": {
+              "description": "Wholesale",
+              "value": "10.0",
+              "title": "B2B",
+              "amount": "19.90",
+              "value_type": "percentage"
+            },
+            "name": "IPod Nano - 8gb - green",
+            "properties": [],
+            "custom": false,
+            "price": "199.00",
+            "admin_graphql_api_id": "gid://shopify/DraftOrderLineItem/994118539"
+          }
+        ],
+        "shipping_address": null,
+        "billing_address": null,
+        "invoice_url": "https://jsmith.myshopify.com/548380009/invoices/994118539/3b3a9a4b",
+        "applied_discount": null,
+        "order_id": null,
+        "shipping_line": {
+          "title": "Custom Shipping",
+          "custom": true,
+          "handle": null,
+          "price": "10.00"
+        },
+        "tax_lines": [],
+        "tags": "",
+        "note_attributes": [],
+        "total_price": "199.00",
+        "subtotal_price": "199.00",
+        "total_tax": "0.00",
+        "admin_graphql_api_id": "gid://shopify/DraftOrder/994118539",
+        "customer": null
+      }
+    }
+    "##,
+    )
+    .unwrap()
+    .into_inner();
+    assert_eq!(draft_order.status, DraftOrderStatus::Open);
+    assert_eq!(
+      draft_order.line_items[0]
+        .applied_discount
+        .as_ref()
+        .map(|d| d.value_type),
+      Some(DiscountValueType::Percentage)
+    );
+  }
+
+  #[test]
+  fn test_serialize_new_draft_order() {
+    let draft_order = NewDraftOrder {
+      line_items: vec![NewDraftOrderLineItem {
+        line_item: NewLineItem::variant(39072856, 10),
+        applied_discount: Some(AppliedDiscount {
+          title: Some("B2B".to_owned()),
+          description: None,
+          value: "10.0".to_owned(),
+          value_type: DiscountValueType::Percentage,
+          amount: None,
+        }),
+      }],
+      ..Default::default()
+    };
+    assert_eq!(
+      serde_json::to_value(&draft_order).unwrap(),
+      serde_json::json!({
+        "line_items": [
+          {
+            "variant_id": 39072856,
+            "quantity": 10,
+            "applied_discount": { "title": "B2B", "value": "10.0", "value_type": "percentage" }
+          }
+        ]
+      })
+    );
+  }
+
+  #[test]
+  #[ignore]
+  fn test_draft_order_list() {
+    let client = crate::client::get_test_client();
+    let page = client.list(&Default::default()).unwrap();
+    println!("{:#?}", page);
+  }
+}
@@ -0,0 +1,174 @@
+use crate::client::AsQueryValue;
+use crate::order::{Address, Customer, NewLineItem, NewOrderCustomer, Property, TaxLines};
+use crate::types::{DateTime, Utc};
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DraftOrderStatus {
+  Open,
+  InvoiceSent,
+  Completed,
+}
+
+impl AsQueryValue for DraftOrderStatus {
+  fn as_query_value(&self) -> String {
+    match *self {
+      DraftOrderStatus::Open => "open",
+      DraftOrderStatus::InvoiceSent => "invoice_sent",
+      DraftOrderStatus::Completed => "completed",
+    }
+    .to_owned()
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscountValueType {
+  FixedAmount,
+  Percentage,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AppliedDiscount {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+  /// The value of the discount, either a fixed amount or a percentage depending on `value_type`.
+  pub value: String,
+  pub value_type: DiscountValueType,
+  /// The applied amount of the discount. Calculated by Shopify.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub amount: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DraftOrderShippingLine {
+  pub title: String,
+  pub price: String,
+  /// Whether this is a custom shipping line. Set to `true` when `handle` is `None`.
+  #[serde(default)]
+  pub custom: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub handle: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DraftOrderLineItem {
+  pub id: Option<i64>,
+  pub variant_id: Option<i64>,
+  pub product_id: Option<i64>,
+  pub title: String,
+  pub variant_title: Option<String>,
+  pub sku: Option<String>,
+  pub vendor: Option<String>,
+  pub name: Option<String>,
+  pub quantity: i64,
+  pub price: String,
+  pub grams: Option<i64>,
+  pub requires_shipping: bool,
+  pub taxable: bool,
+  pub gift_card: bool,
+  pub custom: bool,
+  pub fulfillment_service: Option<String>,
+  pub applied_discount: Option<AppliedDiscount>,
+  pub tax_lines: Vec<TaxLines>,
+  pub properties: Vec<Property>,
+  pub admin_graphql_api_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DraftOrder {
+  pub id: i64,
+  /// The ID of the order created when the draft order was completed.
+  pub order_id: Option<i64>,
+  pub name: String,
+  pub status: DraftOrderStatus,
+  pub email: Option<String>,
+  pub note: Option<String>,
+  pub currency: String,
+  pub customer: Option<Customer>,
+  pub shipping_address: Option<Address>,
+  pub billing_address: Option<Address>,
+  pub line_items: Vec<DraftOrderLineItem>,
+  pub shipping_line: Option<DraftOrderShippingLine>,
+  pub applied_discount: Option<AppliedDiscount>,
+  pub tax_lines: Vec<TaxLines>,
+  pub tax_exempt: bool,
+  pub taxes_included: bool,
+  pub subtotal_price: String,
+  pub total_tax: String,
+  pub total_price: String,
+  pub tags: String,
+  pub note_attributes: Vec<Property>,
+  pub invoice_url: Option<String>,
+  pub invoice_sent_at: Option<DateTime<Utc>>,
+  pub completed_at: Option<DateTime<Utc>>,
+  pub created_at: DateTime<Utc>,
+  pub updated_at: DateTime<Utc>,
+  pub admin_graphql_api_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NewDraftOrderLineItem {
+  #[serde(flatten)]
+  pub line_item: NewLineItem,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub applied_discount: Option<AppliedDiscount>,
+}
+
+impl From<NewLineItem> for NewDraftOrderLineItem {
+  fn from(line_item: NewLineItem) -> Self {
+    NewDraftOrderLineItem {
+      line_item,
+      applied_discount: None,
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct NewDraftOrder {
+  pub line_items: Vec<NewDraftOrderLineItem>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub customer: Option<NewOrderCustomer>,
+  /// Whether to use the customer's default address.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub use_customer_default_address: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub email: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub shipping_address: Option<Address>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub billing_address: Option<Address>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub shipping_line: Option<DraftOrderShippingLine>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub applied_discount: Option<AppliedDiscount>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tax_exempt: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub taxes_included: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub note: Option<String>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub note_attributes: Vec<Property>,
+  /// Comma-separated tags.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tags: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct DraftOrderInvoice {
+  /// The email address that will populate the to line of the email. Defaults to the draft order's email.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub to: Option<String>,
+  /// The email address that will populate the from line of the email. Defaults to the shop's email.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub from: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub bcc: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub subject: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub custom_message: Option<String>,
+}
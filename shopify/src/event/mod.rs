@@ -0,0 +1,200 @@
+use crate::client::{Client, Method};
+use crate::pagination::{GetPage, Paginated};
+use crate::result::*;
+use crate::types::{DateTime, Utc};
+
+mod types;
+pub use self::types::*;
+
+request_query! {
+  pub struct GetEventListParams {
+    pub limit: Option<i64>,
+    pub since_id: Option<i64>,
+    pub created_at_min: Option<DateTime<Utc>>,
+    pub created_at_max: Option<DateTime<Utc>>,
+    pub filter: Option<Vec<EventSubjectType>>,
+    pub verb: Option<String>,
+    pub fields: Option<Vec<String>>,
+  }
+}
+
+request_query! {
+  pub struct GetEventCountParams {
+    pub created_at_min: Option<DateTime<Utc>>,
+    pub created_at_max: Option<DateTime<Utc>>,
+  }
+}
+
+pub trait EventApi {
+  fn list(
+    &self,
+    resource: EventResource,
+    params: &GetEventListParams,
+  ) -> ShopifyResult<Paginated<Vec<Event>>>;
+
+  fn list_page(
+    &self,
+    resource: EventResource,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<Event>>>;
+
+  fn get(&self, id: i64) -> ShopifyResult<Event>;
+
+  /// Counts the events of the shop.
+  fn count(&self, params: &GetEventCountParams) -> ShopifyResult<i64>;
+}
+
+impl EventApi for Client {
+  fn list(
+    &self,
+    resource: EventResource,
+    params: &GetEventListParams,
+  ) -> ShopifyResult<Paginated<Vec<Event>>> {
+    shopify_wrap! {
+      pub struct Res {
+        events: Vec<Event>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      &resource.events_path(),
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_page(
+    &self,
+    resource: EventResource,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<Event>>> {
+    shopify_wrap! {
+      pub struct Res {
+        events: Vec<Event>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      &resource.events_path(),
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn get(&self, id: i64) -> ShopifyResult<Event> {
+    shopify_wrap! {
+      pub struct Res {
+        event: Event,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/events/{}.json", id);
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn count(&self, params: &GetEventCountParams) -> ShopifyResult<i64> {
+    shopify_wrap! {
+      pub struct Res {
+        count: i64,
+      }
+    }
+
+    let res: Res = self.request_with_params(
+      Method::GET,
+      "/admin/api/2023-07/events/count.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.into_inner())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::client::ShopifyRequestQuery;
+
+  #[test]
+  fn test_deserialize_events() {
+    shopify_wrap! {
+      pub struct Res {
+        events: Vec<Event>,
+      }
+    }
+
+    let events = serde_json::from_str::<Res>(
+      r##"
+    {
+      "events": [
+        {
+          "id": 677313116,
+          "subject_id": 921728736,
+          "created_at": "2008-01-10T08:00:00-05:00",
+          "subject_type": "Product",
+          "verb": "create",
+          "arguments": ["IPod Touch 8GB"],
+          "body": null,
+          "message": "Product was created: <a href=\"https://jsmith.myshopify.com/admin/products/921728736\">IPod Touch 8GB</a>.",
+          "author": "Shopify",
+          "description": "Product was created: IPod Touch 8GB.",
+          "path": "/admin/products/921728736"
+        },
+        {
+          "id": 677313117,
+          "subject_id": 1,
+          "created_at": "2008-01-10T08:00:00-05:00",
+          "subject_type": "DraftOrder",
+          "verb": "create",
+          "arguments": [],
+          "body": null,
+          "message": null,
+          "author": null,
+          "description": null,
+          "path": null
+        }
+      ]
+    }
+    "##,
+    )
+    .unwrap()
+    .into_inner();
+    assert_eq!(events[0].subject_type, EventSubjectType::Product);
+    assert_eq!(events[0].verb, "create");
+    assert_eq!(events[1].subject_type, EventSubjectType::Other);
+  }
+
+  #[test]
+  fn test_event_list_params() {
+    let params = GetEventListParams {
+      filter: Some(vec![EventSubjectType::Product, EventSubjectType::Order]),
+      verb: Some("destroy".to_owned()),
+      ..Default::default()
+    };
+    assert_eq!(
+      params.as_query_pairs(),
+      vec![
+        ("filter".to_owned(), "Product,Order".to_owned()),
+        ("verb".to_owned(), "destroy".to_owned()),
+      ]
+    );
+    assert_eq!(
+      EventResource::Product(632910392).events_path(),
+      "/admin/api/2023-07/products/632910392/events.json"
+    );
+  }
+
+  #[test]
+  #[ignore]
+  fn test_list_events() {
+    let client = crate::client::get_test_client();
+    let page = client
+      .list(EventResource::Shop, &Default::default())
+      .unwrap();
+    println!("{:#?}", page);
+  }
+}
@@ -0,0 +1,89 @@
+use crate::client::AsQueryValue;
+use crate::types::{DateTime, Utc, Value};
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+pub enum EventSubjectType {
+  ApiPermission,
+  Article,
+  Blog,
+  Collection,
+  Comment,
+  Order,
+  Page,
+  PriceRule,
+  Product,
+  #[serde(other)]
+  Other,
+}
+
+impl AsQueryValue for EventSubjectType {
+  fn as_query_value(&self) -> String {
+    match *self {
+      EventSubjectType::ApiPermission => "ApiPermission",
+      EventSubjectType::Article => "Article",
+      EventSubjectType::Blog => "Blog",
+      EventSubjectType::Collection => "Collection",
+      EventSubjectType::Comment => "Comment",
+      EventSubjectType::Order => "Order",
+      EventSubjectType::Page => "Page",
+      EventSubjectType::PriceRule => "PriceRule",
+      EventSubjectType::Product => "Product",
+      EventSubjectType::Other => "",
+    }
+    .to_owned()
+  }
+}
+
+/// The events to list: all events of the shop, or the events of one resource.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum EventResource {
+  Shop,
+  Article(i64),
+  Blog(i64),
+  Comment(i64),
+  CustomCollection(i64),
+  SmartCollection(i64),
+  Order(i64),
+  Page(i64),
+  PriceRule(i64),
+  Product(i64),
+}
+
+impl EventResource {
+  pub(crate) fn events_path(&self) -> String {
+    let (resource, id) = match *self {
+      EventResource::Shop => return "/admin/api/2023-07/events.json".to_owned(),
+      EventResource::Article(id) => ("articles", id),
+      EventResource::Blog(id) => ("blogs", id),
+      EventResource::Comment(id) => ("comments", id),
+      EventResource::CustomCollection(id) => ("custom_collections", id),
+      EventResource::SmartCollection(id) => ("smart_collections", id),
+      EventResource::Order(id) => ("orders", id),
+      EventResource::Page(id) => ("pages", id),
+      EventResource::PriceRule(id) => ("price_rules", id),
+      EventResource::Product(id) => ("products", id),
+    };
+    format!("/admin/api/2023-07/{}/{}/events.json", resource, id)
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Event {
+  pub id: i64,
+  pub subject_id: i64,
+  pub subject_type: EventSubjectType,
+  /// The action, e.g. `create`, `update`, `destroy`, `published` or `confirmed`.
+  pub verb: String,
+  /// Values for the placeholders of `message`, e.g. the product title.
+  #[serde(default)]
+  pub arguments: Vec<Value>,
+  pub body: Option<String>,
+  /// A human readable description, e.g. `Product was published by <b>Jane</b>.`.
+  pub message: Option<String>,
+  /// The name of the staff member or app that caused the event.
+  pub author: Option<String>,
+  pub description: Option<String>,
+  /// The admin path of the subject.
+  pub path: Option<String>,
+  pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,369 @@
+//! Handler for the requests Shopify sends to the `callback_url` of a fulfillment service.
+//!
+//! [`CallbackHandler`] verifies the `X-Shopify-Hmac-Sha256` header of every request, parses it and
+//! dispatches it to a [`FulfillmentServiceCallback`] implementation. `GET` requests carry their
+//! parameters in the query string, so Shopify signs the query string instead of the empty body:
+//!
+//! - `GET <callback_url>/fetch_stock.json`
+//! - `GET <callback_url>/fetch_tracking_numbers.json`
+//! - `POST <callback_url>/fulfillment_order_notification`
+//!
+//! The handler works with [`http::Request`]s with a buffered body and implements
+//! [`tower_service::Service`], so it can be mounted in any tower-based server such as axum
+//! after collecting the request body, e.g. with `axum::body::Bytes`.
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use http::{header, Method, Request, Response, StatusCode};
+use serde::Serialize;
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::future::{ready, Ready};
+use std::task::{Context, Poll};
+
+pub const HMAC_HEADER: &str = "X-Shopify-Hmac-Sha256";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FetchStockRequest {
+  /// The SKU to fetch the stock level of. Stock levels of all SKUs are requested when `None`.
+  pub sku: Option<String>,
+  /// The `myshopify.com` domain of the shop.
+  pub shop: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FetchTrackingNumbersRequest {
+  /// The names of the fulfillment orders, e.g. `#1001.1`.
+  pub order_names: Vec<String>,
+  /// The `myshopify.com` domain of the shop.
+  pub shop: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FulfillmentOrderNotificationKind {
+  FulfillmentRequest,
+  CancellationRequest,
+}
+
+/// Tells the fulfillment service to fetch its assigned fulfillment orders.
+///
+/// See [`AssignedFulfillmentOrderApi`](super::AssignedFulfillmentOrderApi).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FulfillmentOrderNotification {
+  pub kind: FulfillmentOrderNotificationKind,
+}
+
+/// Implemented by a fulfillment service to answer Shopify's callbacks.
+pub trait FulfillmentServiceCallback {
+  type Error: Display;
+
+  /// Returns the stock levels keyed by SKU.
+  fn fetch_stock(&self, request: &FetchStockRequest) -> Result<HashMap<String, i64>, Self::Error>;
+
+  /// Returns the tracking numbers keyed by fulfillment order name.
+  fn fetch_tracking_numbers(
+    &self,
+    request: &FetchTrackingNumbersRequest,
+  ) -> Result<HashMap<String, String>, Self::Error>;
+
+  fn fulfillment_order_notification(
+    &self,
+    notification: &FulfillmentOrderNotification,
+  ) -> Result<(), Self::Error>;
+}
+
+#[derive(Debug, Clone)]
+pub struct CallbackHandler<T> {
+  secret: String,
+  callback: T,
+}
+
+impl<T> CallbackHandler<T>
+where
+  T: FulfillmentServiceCallback,
+{
+  /// `secret` is the shared secret of the app that registered the fulfillment service.
+  pub fn new(secret: &str, callback: T) -> Self {
+    CallbackHandler {
+      secret: secret.to_owned(),
+      callback,
+    }
+  }
+
+  pub fn callback(&self) -> &T {
+    &self.callback
+  }
+
+  pub fn handle<B>(&self, request: &Request<B>) -> Response<Vec<u8>>
+  where
+    B: AsRef<[u8]>,
+  {
+    let body = request.body().as_ref();
+    let path = request.uri().path().trim_end_matches(".json");
+    let query = request.uri().query().unwrap_or("");
+    let method = request.method();
+
+    let signed = if method == Method::GET {
+      query.as_bytes()
+    } else {
+      body
+    };
+    let verified = request
+      .headers()
+      .get(HMAC_HEADER)
+      .and_then(|v| v.to_str().ok())
+      .map(|signature| verify_hmac(&self.secret, signed, signature))
+      .unwrap_or(false);
+    if !verified {
+      return text_response(StatusCode::UNAUTHORIZED, "invalid hmac");
+    }
+
+    if path.ends_with("/fetch_stock") && method == Method::GET {
+      let mut req = FetchStockRequest {
+        sku: None,
+        shop: None,
+      };
+      for (k, v) in url::form_urlencoded::parse(query.as_bytes()) {
+        match k.as_ref() {
+          "sku" => req.sku = Some(v.into_owned()),
+          "shop" => req.shop = Some(v.into_owned()),
+          _ => {}
+        }
+      }
+      match self.callback.fetch_stock(&req) {
+        Ok(levels) => json_response(&levels),
+        Err(err) => text_response(StatusCode::INTERNAL_SERVER_ERROR, &err.to_string()),
+      }
+    } else if path.ends_with("/fetch_tracking_numbers") && method == Method::GET {
+      let mut req = FetchTrackingNumbersRequest {
+        order_names: vec![],
+        shop: None,
+      };
+      for (k, v) in url::form_urlencoded::parse(query.as_bytes()) {
+        match k.as_ref() {
+          "order_names[]" | "order_names" => req.order_names.push(v.into_owned()),
+          "shop" => req.shop = Some(v.into_owned()),
+          _ => {}
+        }
+      }
+      match self.callback.fetch_tracking_numbers(&req) {
+        Ok(tracking_numbers) => json_response(&json!({
+          "tracking_numbers": tracking_numbers,
+          "message": "Successfully received the tracking numbers",
+          "success": true,
+        })),
+        Err(err) => text_response(StatusCode::INTERNAL_SERVER_ERROR, &err.to_string()),
+      }
+    } else if path.ends_with("/fulfillment_order_notification") && method == Method::POST {
+      let notification: FulfillmentOrderNotification = match serde_json::from_slice(body) {
+        Ok(v) => v,
+        Err(err) => return text_response(StatusCode::BAD_REQUEST, &err.to_string()),
+      };
+      match self.callback.fulfillment_order_notification(&notification) {
+        Ok(()) => text_response(StatusCode::OK, ""),
+        Err(err) => text_response(StatusCode::INTERNAL_SERVER_ERROR, &err.to_string()),
+      }
+    } else {
+      text_response(StatusCode::NOT_FOUND, "not found")
+    }
+  }
+}
+
+impl<T, B> tower_service::Service<Request<B>> for CallbackHandler<T>
+where
+  T: FulfillmentServiceCallback,
+  B: AsRef<[u8]>,
+{
+  type Response = Response<Vec<u8>>;
+  type Error = Infallible;
+  type Future = Ready<Result<Self::Response, Self::Error>>;
+
+  fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+    Poll::Ready(Ok(()))
+  }
+
+  fn call(&mut self, request: Request<B>) -> Self::Future {
+    ready(Ok(self.handle(&request)))
+  }
+}
+
+/// Verifies the base64 encoded HMAC-SHA256 `signature` of `message`, which is the body of a `POST`
+/// callback or the raw query string of a `GET` callback.
+pub fn verify_hmac(secret: &str, message: &[u8], signature: &str) -> bool {
+  let signature = match BASE64.decode(signature.trim()) {
+    Ok(v) => v,
+    Err(_) => return false,
+  };
+  let mut mac =
+    Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
+  mac.update(message);
+  mac.verify_slice(&signature).is_ok()
+}
+
+fn json_response<T: Serialize>(value: &T) -> Response<Vec<u8>> {
+  match serde_json::to_vec(value) {
+    Ok(body) => Response::builder()
+      .status(StatusCode::OK)
+      .header(header::CONTENT_TYPE, "application/json")
+      .body(body)
+      .unwrap(),
+    Err(err) => text_response(StatusCode::INTERNAL_SERVER_ERROR, &err.to_string()),
+  }
+}
+
+fn text_response(status: StatusCode, body: &str) -> Response<Vec<u8>> {
+  Response::builder()
+    .status(status)
+    .header(header::CONTENT_TYPE, "text/plain")
+    .body(body.as_bytes().to_vec())
+    .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::cell::RefCell;
+
+  const SECRET: &str = "hush";
+
+  #[derive(Default)]
+  struct Warehouse {
+    notifications: RefCell<Vec<FulfillmentOrderNotification>>,
+  }
+
+  impl FulfillmentServiceCallback for Warehouse {
+    type Error = String;
+
+    fn fetch_stock(&self, request: &FetchStockRequest) -> Result<HashMap<String, i64>, String> {
+      let mut levels = HashMap::new();
+      levels.insert(request.sku.clone().unwrap_or_default(), 5);
+      Ok(levels)
+    }
+
+    fn fetch_tracking_numbers(
+      &self,
+      request: &FetchTrackingNumbersRequest,
+    ) -> Result<HashMap<String, String>, String> {
+      Ok(
+        request
+          .order_names
+          .iter()
+          .map(|name| (name.clone(), format!("TRACK{}", name)))
+          .collect(),
+      )
+    }
+
+    fn fulfillment_order_notification(
+      &self,
+      notification: &FulfillmentOrderNotification,
+    ) -> Result<(), String> {
+      self.notifications.borrow_mut().push(notification.clone());
+      Ok(())
+    }
+  }
+
+  fn sign(body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(SECRET.as_bytes()).unwrap();
+    mac.update(body);
+    BASE64.encode(mac.finalize().into_bytes())
+  }
+
+  fn request(method: Method, uri: &str, body: &str, signature: &str) -> Request<Vec<u8>> {
+    Request::builder()
+      .method(method)
+      .uri(uri)
+      .header(HMAC_HEADER, signature)
+      .body(body.as_bytes().to_vec())
+      .unwrap()
+  }
+
+  #[test]
+  fn test_rejects_invalid_hmac() {
+    let handler = CallbackHandler::new(SECRET, Warehouse::default());
+    let res = handler.handle(&request(
+      Method::GET,
+      "https://example.com/shopify/fetch_stock.json?sku=A",
+      "",
+      &BASE64.encode(b"invalid"),
+    ));
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+  }
+
+  #[test]
+  fn test_rejects_tampered_query() {
+    let handler = CallbackHandler::new(SECRET, Warehouse::default());
+    let res = handler.handle(&request(
+      Method::GET,
+      "https://example.com/shopify/fetch_stock.json?sku=B-2&shop=test.myshopify.com",
+      "",
+      &sign(b"sku=A-1&shop=test.myshopify.com"),
+    ));
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+
+    let res = handler.handle(&request(
+      Method::GET,
+      "https://example.com/shopify/fetch_stock.json?sku=B-2&shop=test.myshopify.com",
+      "",
+      &sign(b""),
+    ));
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+  }
+
+  #[test]
+  fn test_fetch_stock() {
+    let handler = CallbackHandler::new(SECRET, Warehouse::default());
+    let res = handler.handle(&request(
+      Method::GET,
+      "https://example.com/shopify/fetch_stock.json?sku=A-1&shop=test.myshopify.com",
+      "",
+      &sign(b"sku=A-1&shop=test.myshopify.com"),
+    ));
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!(body, json!({ "A-1": 5 }));
+  }
+
+  #[test]
+  fn test_fetch_tracking_numbers() {
+    let handler = CallbackHandler::new(SECRET, Warehouse::default());
+    let res = handler.handle(&request(
+      Method::GET,
+      "https://example.com/shopify/fetch_tracking_numbers.json?order_names%5B%5D=%231001.1&order_names%5B%5D=%231002.1",
+      "",
+      &sign(b"order_names%5B%5D=%231001.1&order_names%5B%5D=%231002.1"),
+    ));
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!(
+      body,
+      json!({
+        "tracking_numbers": { "#1001.1": "TRACK#1001.1", "#1002.1": "TRACK#1002.1" },
+        "message": "Successfully received the tracking numbers",
+        "success": true,
+      })
+    );
+  }
+
+  #[test]
+  fn test_fulfillment_order_notification() {
+    let handler = CallbackHandler::new(SECRET, Warehouse::default());
+    let body = r#"{"kind":"FULFILLMENT_REQUEST"}"#;
+    let res = handler.handle(&request(
+      Method::POST,
+      "https://example.com/shopify/fulfillment_order_notification",
+      body,
+      &sign(body.as_bytes()),
+    ));
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+      handler.callback().notifications.borrow().as_slice(),
+      &[FulfillmentOrderNotification {
+        kind: FulfillmentOrderNotificationKind::FulfillmentRequest
+      }]
+    );
+  }
+}
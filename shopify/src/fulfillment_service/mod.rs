@@ -1,8 +1,11 @@
 use crate::client::{Client, Method};
+use crate::order::FulfillmentOrder;
 use crate::result::*;
 
 mod types;
 pub use self::types::*;
+#[cfg(feature = "callback")]
+pub mod callback;
 
 pub trait FulfillmentServiceApi {
   fn get_list(
@@ -91,15 +94,141 @@ impl FulfillmentServiceApi for Client {
   }
 
   fn delete(&self, id: i64) -> ShopifyResult<()> {
-    use serde_json::Value;
+    let path = format!("/admin/fulfillment_services/{}.json", id);
+    self.request_no_content(Method::DELETE, &path, std::convert::identity)
+  }
+}
+
+/// Endpoints used by a fulfillment service to process the fulfillment orders assigned to its locations.
+pub trait AssignedFulfillmentOrderApi {
+  fn get_assigned_fulfillment_orders(
+    &self,
+    params: &GetAssignedFulfillmentOrdersParams,
+  ) -> ShopifyResult<Vec<FulfillmentOrder>>;
+
+  fn accept_fulfillment_request(
+    &self,
+    fulfillment_order_id: i64,
+    message: Option<&str>,
+  ) -> ShopifyResult<FulfillmentOrder>;
+
+  fn reject_fulfillment_request(
+    &self,
+    fulfillment_order_id: i64,
+    rejection: &RejectFulfillmentRequest,
+  ) -> ShopifyResult<FulfillmentOrder>;
+
+  fn accept_cancellation_request(
+    &self,
+    fulfillment_order_id: i64,
+    message: Option<&str>,
+  ) -> ShopifyResult<FulfillmentOrder>;
+
+  fn reject_cancellation_request(
+    &self,
+    fulfillment_order_id: i64,
+    message: Option<&str>,
+  ) -> ShopifyResult<FulfillmentOrder>;
+}
+
+impl AssignedFulfillmentOrderApi for Client {
+  fn get_assigned_fulfillment_orders(
+    &self,
+    params: &GetAssignedFulfillmentOrdersParams,
+  ) -> ShopifyResult<Vec<FulfillmentOrder>> {
     shopify_wrap! {
       pub struct Res {
-        fulfillment_service: FulfillmentService,
+        fulfillment_orders: Vec<FulfillmentOrder>,
       }
     }
-    let path = format!("/admin/fulfillment_services/{}.json", id);
-    self.request::<Value, _>(Method::DELETE, &path, std::convert::identity)?;
-    Ok(())
+
+    let res: Res = self.request_with_params(
+      Method::GET,
+      "/admin/api/2023-07/assigned_fulfillment_orders.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.into_inner())
+  }
+
+  fn accept_fulfillment_request(
+    &self,
+    fulfillment_order_id: i64,
+    message: Option<&str>,
+  ) -> ShopifyResult<FulfillmentOrder> {
+    shopify_wrap! {
+      pub struct Res {
+        fulfillment_order: FulfillmentOrder,
+      }
+    }
+    let path = format!(
+      "/admin/api/2023-07/fulfillment_orders/{}/fulfillment_request/accept.json",
+      fulfillment_order_id
+    );
+    let res: Res = self.request(Method::POST, &path, move |b| {
+      b.json(&json!({ "fulfillment_request": { "message": message } }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn reject_fulfillment_request(
+    &self,
+    fulfillment_order_id: i64,
+    rejection: &RejectFulfillmentRequest,
+  ) -> ShopifyResult<FulfillmentOrder> {
+    shopify_wrap! {
+      pub struct Res {
+        fulfillment_order: FulfillmentOrder,
+      }
+    }
+    let path = format!(
+      "/admin/api/2023-07/fulfillment_orders/{}/fulfillment_request/reject.json",
+      fulfillment_order_id
+    );
+    let res: Res = self.request(Method::POST, &path, move |b| {
+      b.json(&json!({ "fulfillment_request": rejection }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn accept_cancellation_request(
+    &self,
+    fulfillment_order_id: i64,
+    message: Option<&str>,
+  ) -> ShopifyResult<FulfillmentOrder> {
+    shopify_wrap! {
+      pub struct Res {
+        fulfillment_order: FulfillmentOrder,
+      }
+    }
+    let path = format!(
+      "/admin/api/2023-07/fulfillment_orders/{}/cancellation_request/accept.json",
+      fulfillment_order_id
+    );
+    let res: Res = self.request(Method::POST, &path, move |b| {
+      b.json(&json!({ "cancellation_request": { "message": message } }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn reject_cancellation_request(
+    &self,
+    fulfillment_order_id: i64,
+    message: Option<&str>,
+  ) -> ShopifyResult<FulfillmentOrder> {
+    shopify_wrap! {
+      pub struct Res {
+        fulfillment_order: FulfillmentOrder,
+      }
+    }
+    let path = format!(
+      "/admin/api/2023-07/fulfillment_orders/{}/cancellation_request/reject.json",
+      fulfillment_order_id
+    );
+    let res: Res = self.request(Method::POST, &path, move |b| {
+      b.json(&json!({ "cancellation_request": { "message": message } }))
+    })?;
+    Ok(res.into_inner())
   }
 }
 
@@ -156,6 +285,39 @@ mod tests {
     println!("{:#?}", service);
   }
 
+  #[test]
+  fn test_assigned_fulfillment_orders_params() {
+    use crate::client::ShopifyRequestQuery;
+    let params = GetAssignedFulfillmentOrdersParams {
+      assignment_status: Some(AssignmentStatus::FulfillmentRequested),
+      location_ids: Some(vec![24826418, 48752903]),
+    };
+    assert_eq!(
+      params.as_query_pairs(),
+      vec![
+        (
+          "assignment_status".to_owned(),
+          "fulfillment_requested".to_owned()
+        ),
+        ("location_ids[]".to_owned(), "24826418".to_owned()),
+        ("location_ids[]".to_owned(), "48752903".to_owned()),
+      ]
+    );
+  }
+
+  #[test]
+  #[ignore]
+  fn test_get_assigned_fulfillment_orders() {
+    let client = crate::client::get_test_client();
+    let orders = client
+      .get_assigned_fulfillment_orders(&GetAssignedFulfillmentOrdersParams {
+        assignment_status: Some(AssignmentStatus::FulfillmentRequested),
+        ..Default::default()
+      })
+      .unwrap();
+    println!("{:#?}", orders);
+  }
+
   #[test]
   #[ignore]
   fn test_fulfillment_service_delete() {
@@ -1,3 +1,5 @@
+use crate::client::{AsQueryValue, ShopifyRequestQuery};
+
 #[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum FulfillmentServiceScope {
@@ -57,3 +59,72 @@ pub struct UpdateFulfillmentService {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub fulfillment_orders_opt_in: Option<bool>,
 }
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AssignmentStatus {
+  /// Fulfillment orders for which the merchant has requested cancellation.
+  CancellationRequested,
+  /// Fulfillment orders for which the merchant has requested fulfillment.
+  FulfillmentRequested,
+  /// Fulfillment orders for which the fulfillment request has been accepted.
+  FulfillmentAccepted,
+}
+
+impl AsQueryValue for AssignmentStatus {
+  fn as_query_value(&self) -> String {
+    match *self {
+      AssignmentStatus::CancellationRequested => "cancellation_requested",
+      AssignmentStatus::FulfillmentRequested => "fulfillment_requested",
+      AssignmentStatus::FulfillmentAccepted => "fulfillment_accepted",
+    }
+    .to_owned()
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct GetAssignedFulfillmentOrdersParams {
+  pub assignment_status: Option<AssignmentStatus>,
+  pub location_ids: Option<Vec<i64>>,
+}
+
+impl ShopifyRequestQuery for GetAssignedFulfillmentOrdersParams {
+  fn as_query_pairs(&self) -> Vec<(String, String)> {
+    let mut pairs = vec![];
+    if let Some(ref status) = self.assignment_status {
+      pairs.push(("assignment_status".to_owned(), status.as_query_value()));
+    }
+    // Shopify expects an array parameter here instead of comma-separated IDs.
+    for id in self.location_ids.iter().flatten() {
+      pairs.push(("location_ids[]".to_owned(), id.to_string()));
+    }
+    pairs
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FulfillmentRejectionReason {
+  IncorrectAddress,
+  IneligibleProduct,
+  InventoryOutOfStock,
+  UndeliverableDestination,
+  Other,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RejectedLineItem {
+  pub fulfillment_order_line_item_id: i64,
+  /// The reason why this line item could not be fulfilled.
+  pub message: String,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct RejectFulfillmentRequest {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub message: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub reason: Option<FulfillmentRejectionReason>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub line_items: Option<Vec<RejectedLineItem>>,
+}
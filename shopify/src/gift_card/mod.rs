@@ -0,0 +1,264 @@
+use crate::client::{Client, Method};
+use crate::pagination::{GetPage, Paginated};
+use crate::result::*;
+
+mod types;
+pub use self::types::*;
+
+request_query! {
+  pub struct GetGiftCardListParams {
+    pub limit: Option<i64>,
+    pub since_id: Option<i64>,
+    pub status: Option<GiftCardStatus>,
+    pub fields: Option<Vec<String>>,
+  }
+}
+
+request_query! {
+  pub struct SearchGiftCardParams {
+    pub query: Option<String>,
+    pub order: Option<String>,
+    pub limit: Option<i64>,
+    pub fields: Option<Vec<String>>,
+  }
+}
+
+request_query! {
+  pub struct GetGiftCardCountParams {
+    pub status: Option<GiftCardStatus>,
+  }
+}
+
+pub trait GiftCardApi {
+  fn list(&self, params: &GetGiftCardListParams) -> ShopifyResult<Paginated<Vec<GiftCard>>>;
+
+  fn list_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<GiftCard>>>;
+
+  /// Searches gift cards, e.g. with a `query` of `last_characters:mnop` or `balance:>10`.
+  fn search(&self, params: &SearchGiftCardParams) -> ShopifyResult<Paginated<Vec<GiftCard>>>;
+
+  fn search_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<GiftCard>>>;
+
+  fn get(&self, id: i64) -> ShopifyResult<GiftCard>;
+
+  fn count(&self, params: &GetGiftCardCountParams) -> ShopifyResult<i64>;
+
+  fn create(&self, gift_card: &NewGiftCard) -> ShopifyResult<GiftCard>;
+
+  fn update(&self, id: i64, gift_card: &UpdateGiftCard) -> ShopifyResult<GiftCard>;
+
+  /// Disables the gift card. Disabled gift cards can't be enabled again.
+  fn disable(&self, id: i64) -> ShopifyResult<GiftCard>;
+}
+
+impl GiftCardApi for Client {
+  fn list(&self, params: &GetGiftCardListParams) -> ShopifyResult<Paginated<Vec<GiftCard>>> {
+    shopify_wrap! {
+      pub struct Res {
+        gift_cards: Vec<GiftCard>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/gift_cards.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<GiftCard>>> {
+    shopify_wrap! {
+      pub struct Res {
+        gift_cards: Vec<GiftCard>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/gift_cards.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn search(&self, params: &SearchGiftCardParams) -> ShopifyResult<Paginated<Vec<GiftCard>>> {
+    shopify_wrap! {
+      pub struct Res {
+        gift_cards: Vec<GiftCard>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/gift_cards/search.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn search_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<GiftCard>>> {
+    shopify_wrap! {
+      pub struct Res {
+        gift_cards: Vec<GiftCard>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/gift_cards/search.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn get(&self, id: i64) -> ShopifyResult<GiftCard> {
+    shopify_wrap! {
+      pub struct Res {
+        gift_card: GiftCard,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/gift_cards/{}.json", id);
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn count(&self, params: &GetGiftCardCountParams) -> ShopifyResult<i64> {
+    shopify_wrap! {
+      pub struct Res {
+        count: i64,
+      }
+    }
+
+    let res: Res = self.request_with_params(
+      Method::GET,
+      "/admin/api/2023-07/gift_cards/count.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.into_inner())
+  }
+
+  fn create(&self, gift_card: &NewGiftCard) -> ShopifyResult<GiftCard> {
+    shopify_wrap! {
+      pub struct Res {
+        gift_card: GiftCard,
+      }
+    }
+
+    let res: Res = self.request(
+      Method::POST,
+      "/admin/api/2023-07/gift_cards.json",
+      move |b| b.json(&json!({ "gift_card": gift_card })),
+    )?;
+    Ok(res.into_inner())
+  }
+
+  fn update(&self, id: i64, gift_card: &UpdateGiftCard) -> ShopifyResult<GiftCard> {
+    shopify_wrap! {
+      pub struct Res {
+        gift_card: GiftCard,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/gift_cards/{}.json", id);
+    let res: Res = self.request(Method::PUT, &path, move |b| {
+      b.json(&json!({ "gift_card": gift_card }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn disable(&self, id: i64) -> ShopifyResult<GiftCard> {
+    shopify_wrap! {
+      pub struct Res {
+        gift_card: GiftCard,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/gift_cards/{}/disable.json", id);
+    let res: Res = self.request(Method::POST, &path, |b| {
+      b.json(&json!({ "gift_card": { "id": id } }))
+    })?;
+    Ok(res.into_inner())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::order::Money;
+  use chrono::NaiveDate;
+
+  #[test]
+  fn test_deserialize_gift_card() {
+    shopify_wrap! {
+      pub struct Res {
+        gift_card: GiftCard,
+      }
+    }
+
+    let gift_card = serde_json::from_str::<Res>(
+      r##"
+    {
+      "gift_card": {
+        "id": 1063936318,
+        "balance": "100.00",
+        "created_at": "2023-07-11T18:33:22-04:00",
+        "updated_at": "2023-07-11T18:33:22-04:00",
+        "currency": "CAD",
+        "initial_value": "100.00",
+        "disabled_at": null,
+        "line_item_id": null,
+        "api_client_id": 755357713,
+        "user_id": null,
+        "customer_id": null,
+        "note": null,
+        "expires_on": "2024-01-01",
+        "template_suffix": null,
+        "last_characters": "0e0e",
+        "order_id": null,
+        "code": "1234 4567 890a 0e0e",
+        "admin_graphql_api_id": "gid://shopify/GiftCard/1063936318"
+      }
+    }
+    "##,
+    )
+    .unwrap()
+    .into_inner();
+    assert_eq!(gift_card.balance_money(), Money::new("100.00", "CAD"));
+    assert_eq!(gift_card.expires_on, NaiveDate::from_ymd_opt(2024, 1, 1));
+    assert!(!gift_card.is_disabled());
+  }
+
+  #[test]
+  fn test_serialize_new_gift_card() {
+    let gift_card = NewGiftCard {
+      note: Some("Refund for #1001".to_owned()),
+      expires_on: NaiveDate::from_ymd_opt(2024, 1, 1),
+      customer_id: Some(207119551),
+      ..NewGiftCard::new("25.00")
+    };
+    assert_eq!(
+      serde_json::to_value(gift_card).unwrap(),
+      serde_json::json!({
+        "initial_value": "25.00",
+        "note": "Refund for #1001",
+        "expires_on": "2024-01-01",
+        "customer_id": 207119551
+      })
+    );
+  }
+
+  #[test]
+  #[ignore]
+  fn test_gift_card_list() {
+    let client = crate::client::get_test_client();
+    let page = client.list(&Default::default()).unwrap();
+    println!("{:#?}", page);
+  }
+}
@@ -0,0 +1,97 @@
+use crate::client::AsQueryValue;
+use crate::order::Money;
+use crate::types::{DateTime, Utc};
+use chrono::NaiveDate;
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum GiftCardStatus {
+  Enabled,
+  Disabled,
+}
+
+impl AsQueryValue for GiftCardStatus {
+  fn as_query_value(&self) -> String {
+    match *self {
+      GiftCardStatus::Enabled => "enabled",
+      GiftCardStatus::Disabled => "disabled",
+    }
+    .to_owned()
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GiftCard {
+  pub id: i64,
+  pub initial_value: String,
+  pub balance: String,
+  pub currency: String,
+  /// The full code is only returned when the gift card is created.
+  pub code: Option<String>,
+  pub last_characters: String,
+  pub note: Option<String>,
+  pub expires_on: Option<NaiveDate>,
+  pub customer_id: Option<i64>,
+  pub order_id: Option<i64>,
+  pub line_item_id: Option<i64>,
+  pub user_id: Option<i64>,
+  pub api_client_id: Option<i64>,
+  pub template_suffix: Option<String>,
+  pub disabled_at: Option<DateTime<Utc>>,
+  pub created_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl GiftCard {
+  pub fn initial_value_money(&self) -> Money {
+    Money::new(&self.initial_value, &self.currency)
+  }
+
+  pub fn balance_money(&self) -> Money {
+    Money::new(&self.balance, &self.currency)
+  }
+
+  pub fn is_disabled(&self) -> bool {
+    self.disabled_at.is_some()
+  }
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct NewGiftCard {
+  /// The amount in the shop's currency.
+  pub initial_value: String,
+  /// 8 to 20 alphanumeric characters. Generated by Shopify if not set.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub code: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub note: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub expires_on: Option<NaiveDate>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub customer_id: Option<i64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub template_suffix: Option<String>,
+}
+
+impl NewGiftCard {
+  pub fn new(initial_value: &str) -> Self {
+    NewGiftCard {
+      initial_value: initial_value.to_owned(),
+      ..Default::default()
+    }
+  }
+}
+
+/// The fields of a gift card that can be changed. The value and code can't be updated.
+#[derive(Debug, Serialize, Default)]
+pub struct UpdateGiftCard {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub note: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub expires_on: Option<NaiveDate>,
+  /// A customer can only be set on gift cards without one.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub customer_id: Option<i64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub template_suffix: Option<String>,
+}
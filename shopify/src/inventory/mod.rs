@@ -1,12 +1,30 @@
 use crate::client::{Client, Method};
+use crate::pagination::{GetPage, Paginated, PaginatedIter};
 use crate::result::*;
+use crate::types::{DateTime, Utc};
 
 mod types;
 pub use self::types::*;
 
+request_query! {
+  pub struct GetLocationInventoryLevelsParams {
+    pub limit: Option<i64>,
+  }
+}
+
 pub trait LocationApi {
   fn get_list(&self) -> ShopifyResult<Vec<Location>>;
   fn get(&self, id: i64) -> ShopifyResult<Location>;
+  fn list_inventory_levels(
+    &self,
+    location_id: i64,
+    params: &GetLocationInventoryLevelsParams,
+  ) -> ShopifyResult<Paginated<Vec<InventoryLevel>>>;
+  fn list_inventory_levels_page(
+    &self,
+    location_id: i64,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<InventoryLevel>>>;
 }
 
 impl LocationApi for Client {
@@ -31,37 +49,190 @@ impl LocationApi for Client {
     let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
     Ok(res.into_inner())
   }
+
+  fn list_inventory_levels(
+    &self,
+    location_id: i64,
+    params: &GetLocationInventoryLevelsParams,
+  ) -> ShopifyResult<Paginated<Vec<InventoryLevel>>> {
+    shopify_wrap! {
+      pub struct Res {
+        inventory_levels: Vec<InventoryLevel>,
+      }
+    }
+
+    let path = format!(
+      "/admin/api/2023-07/locations/{}/inventory_levels.json",
+      location_id
+    );
+    let res: Paginated<Res> =
+      self.request_with_params_paginated(Method::GET, &path, params, std::convert::identity)?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_inventory_levels_page(
+    &self,
+    location_id: i64,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<InventoryLevel>>> {
+    shopify_wrap! {
+      pub struct Res {
+        inventory_levels: Vec<InventoryLevel>,
+      }
+    }
+
+    let path = format!(
+      "/admin/api/2023-07/locations/{}/inventory_levels.json",
+      location_id
+    );
+    let res: Paginated<Res> =
+      self.request_with_params_paginated(Method::GET, &path, params, std::convert::identity)?;
+    Ok(res.map(|p| p.into_inner()))
+  }
 }
 
 request_query! {
   pub struct GetInventoryLevelsParams {
     pub limit: Option<i64>,
-    pub page: Option<i64>,
     pub inventory_item_ids: Option<Vec<i64>>,
     pub location_ids: Option<Vec<i64>>,
+    pub updated_at_min: Option<DateTime<Utc>>,
   }
 }
 
 pub trait InventoryLevelApi {
+  /// Returns the inventory levels of all pages.
   fn get_list(&self, params: &GetInventoryLevelsParams) -> ShopifyResult<Vec<InventoryLevel>>;
+
+  fn list(
+    &self,
+    params: &GetInventoryLevelsParams,
+  ) -> ShopifyResult<Paginated<Vec<InventoryLevel>>>;
+
+  fn list_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<InventoryLevel>>>;
+
+  /// Iterates over the inventory levels of all pages, fetching the next page when needed.
+  fn list_iter(
+    &self,
+    params: &GetInventoryLevelsParams,
+  ) -> ShopifyResult<PaginatedIter<'_, InventoryLevel>>;
+
+  fn set(&self, level: &SetInventoryLevel) -> ShopifyResult<InventoryLevel>;
+
+  fn adjust(&self, adjustment: &AdjustInventoryLevel) -> ShopifyResult<InventoryLevel>;
+
+  fn connect(&self, connection: &ConnectInventoryLevel) -> ShopifyResult<InventoryLevel>;
+
+  fn delete(&self, inventory_item_id: i64, location_id: i64) -> ShopifyResult<()>;
 }
 
 impl InventoryLevelApi for Client {
   fn get_list(&self, params: &GetInventoryLevelsParams) -> ShopifyResult<Vec<InventoryLevel>> {
+    self.list_iter(params)?.collect()
+  }
+
+  fn list(
+    &self,
+    params: &GetInventoryLevelsParams,
+  ) -> ShopifyResult<Paginated<Vec<InventoryLevel>>> {
     shopify_wrap! {
       pub struct Res {
         inventory_levels: Vec<InventoryLevel>,
       }
     }
 
-    let res: Res = self.request_with_params(
+    let res: Paginated<Res> = self.request_with_params_paginated(
       Method::GET,
-      "/admin/inventory_levels.json",
+      "/admin/api/2023-07/inventory_levels.json",
       params,
       std::convert::identity,
     )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<InventoryLevel>>> {
+    shopify_wrap! {
+      pub struct Res {
+        inventory_levels: Vec<InventoryLevel>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/inventory_levels.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_iter(
+    &self,
+    params: &GetInventoryLevelsParams,
+  ) -> ShopifyResult<PaginatedIter<'_, InventoryLevel>> {
+    let limit = params.limit;
+    let first = InventoryLevelApi::list(self, params)?;
+    Ok(first.into_iter_with(move |page| {
+      let page = GetPage {
+        limit,
+        ..page.clone()
+      };
+      InventoryLevelApi::list_page(self, &page)
+    }))
+  }
+
+  fn set(&self, level: &SetInventoryLevel) -> ShopifyResult<InventoryLevel> {
+    shopify_wrap! {
+      pub struct Res {
+        inventory_level: InventoryLevel,
+      }
+    }
+
+    let res: Res = self.request(
+      Method::POST,
+      "/admin/api/2023-07/inventory_levels/set.json",
+      move |b| b.json(level),
+    )?;
     Ok(res.into_inner())
   }
+
+  fn adjust(&self, adjustment: &AdjustInventoryLevel) -> ShopifyResult<InventoryLevel> {
+    shopify_wrap! {
+      pub struct Res {
+        inventory_level: InventoryLevel,
+      }
+    }
+
+    let res: Res = self.request(
+      Method::POST,
+      "/admin/api/2023-07/inventory_levels/adjust.json",
+      move |b| b.json(adjustment),
+    )?;
+    Ok(res.into_inner())
+  }
+
+  fn connect(&self, connection: &ConnectInventoryLevel) -> ShopifyResult<InventoryLevel> {
+    shopify_wrap! {
+      pub struct Res {
+        inventory_level: InventoryLevel,
+      }
+    }
+
+    let res: Res = self.request(
+      Method::POST,
+      "/admin/api/2023-07/inventory_levels/connect.json",
+      move |b| b.json(connection),
+    )?;
+    Ok(res.into_inner())
+  }
+
+  fn delete(&self, inventory_item_id: i64, location_id: i64) -> ShopifyResult<()> {
+    let path = format!(
+      "/admin/api/2023-07/inventory_levels.json?inventory_item_id={}&location_id={}",
+      inventory_item_id, location_id
+    );
+    self.request_no_content(Method::DELETE, &path, std::convert::identity)
+  }
 }
 
 #[cfg(test)]
@@ -87,6 +258,20 @@ mod tests {
     println!("{:#?}", list);
   }
 
+  #[test]
+  #[ignore]
+  fn test_location_list_inventory_levels() {
+    let client = create_test_client();
+    let location_id = var("LOCATION_ID").unwrap().parse().unwrap();
+    let page = inventory::LocationApi::list_inventory_levels(
+      &client,
+      location_id,
+      &GetLocationInventoryLevelsParams { limit: Some(10) },
+    )
+    .unwrap();
+    println!("{:#?}", page);
+  }
+
   #[test]
   #[ignore]
   fn test_inventory_level_get_list() {
@@ -102,4 +287,56 @@ mod tests {
     .unwrap();
     println!("{:#?}", list);
   }
+
+  #[test]
+  fn test_inventory_level_deserialize() {
+    shopify_wrap! {
+      pub struct Res {
+        inventory_levels: Vec<InventoryLevel>,
+      }
+    }
+
+    let res: Res = serde_json::from_str(
+      r##"
+    {
+      "inventory_levels": [
+        {
+          "inventory_item_id": 808950810,
+          "location_id": 655441491,
+          "available": 1,
+          "updated_at": "2023-07-05T19:07:49-04:00",
+          "admin_graphql_api_id": "gid://shopify/InventoryLevel/655441491?inventory_item_id=808950810"
+        },
+        {
+          "inventory_item_id": 808950810,
+          "location_id": 905684977,
+          "available": null,
+          "updated_at": "2023-07-05T19:07:49-04:00",
+          "admin_graphql_api_id": "gid://shopify/InventoryLevel/905684977?inventory_item_id=808950810"
+        }
+      ]
+    }
+    "##,
+    )
+    .unwrap();
+    let levels = res.into_inner();
+    assert_eq!(levels[0].available, Some(1));
+    assert_eq!(levels[1].available, None);
+  }
+
+  #[test]
+  #[ignore]
+  fn test_inventory_level_adjust() {
+    let client = create_test_client();
+    let level = inventory::InventoryLevelApi::adjust(
+      &client,
+      &AdjustInventoryLevel {
+        inventory_item_id: var("INVENTORY_ITEM_ID").unwrap().parse().unwrap(),
+        location_id: var("LOCATION_ID").unwrap().parse().unwrap(),
+        available_adjustment: 1,
+      },
+    )
+    .unwrap();
+    println!("{:#?}", level);
+  }
 }
@@ -1,5 +1,4 @@
 use chrono::{DateTime, Utc};
-use serde_json::Value;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Location {
@@ -25,7 +24,34 @@ pub struct Location {
 pub struct InventoryLevel {
   pub inventory_item_id: i64,
   pub location_id: i64,
-  pub available: Value,
+  pub available: Option<i64>,
   pub admin_graphql_api_id: String,
   pub updated_at: DateTime<Utc>,
 }
+
+#[derive(Debug, Serialize)]
+pub struct SetInventoryLevel {
+  pub inventory_item_id: i64,
+  pub location_id: i64,
+  pub available: i64,
+  /// Whether inventory for any previously connected locations will be set to 0 and the locations disconnected.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub disconnect_if_necessary: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdjustInventoryLevel {
+  pub inventory_item_id: i64,
+  pub location_id: i64,
+  /// The amount to adjust the available inventory quantity by. Negative values decrease stock.
+  pub available_adjustment: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ConnectInventoryLevel {
+  pub inventory_item_id: i64,
+  pub location_id: i64,
+  /// Whether inventory for any previously connected locations will be relocated.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub relocate_if_necessary: Option<bool>,
+}
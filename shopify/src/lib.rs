@@ -8,10 +8,25 @@ pub mod result;
 pub mod client;
 mod types;
 
+pub mod abandoned_checkout;
+pub mod blog;
+pub mod collection;
+pub mod draft_order;
+pub mod event;
 pub mod fulfillment_service;
+pub mod gift_card;
 pub mod inventory;
+pub mod metafield;
+pub mod metaobject;
 pub mod order;
+pub mod page;
 pub mod pagination;
+pub mod price_rule;
 pub mod product;
+pub mod redirect;
 pub mod shop;
+pub mod shopify_payments;
+pub mod tender_transaction;
+pub mod theme;
+pub mod tracking;
 pub mod variant;
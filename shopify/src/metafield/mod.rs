@@ -0,0 +1,291 @@
+//! Metafields of the shop and its resources.
+//!
+//! ```no_run
+//! # use shopify::client::Client;
+//! use shopify::metafield::*;
+//!
+//! # let client = Client::new("https://example.myshopify.com", "key", "password").unwrap();
+//! let owner = MetafieldOwner::Variant(39072856);
+//! client
+//!   .create_metafield(
+//!     owner,
+//!     &NewMetafield::new(
+//!       "customs",
+//!       "hs_code",
+//!       &MetafieldValue::SingleLineTextField("8517.12".to_owned()),
+//!     ),
+//!   )
+//!   .unwrap();
+//! ```
+
+use crate::client::{Client, Method};
+use crate::pagination::{GetPage, Paginated};
+use crate::result::*;
+use crate::types::{DateTime, Utc};
+
+mod types;
+pub use self::types::*;
+
+request_query! {
+  pub struct GetMetafieldListParams {
+    pub limit: Option<i64>,
+    pub since_id: Option<i64>,
+    pub namespace: Option<String>,
+    pub key: Option<String>,
+    pub created_at_min: Option<DateTime<Utc>>,
+    pub created_at_max: Option<DateTime<Utc>>,
+    pub updated_at_min: Option<DateTime<Utc>>,
+    pub updated_at_max: Option<DateTime<Utc>>,
+    pub fields: Option<Vec<String>>,
+  }
+}
+
+pub trait MetafieldApi {
+  fn list_metafields(
+    &self,
+    owner: MetafieldOwner,
+    params: &GetMetafieldListParams,
+  ) -> ShopifyResult<Paginated<Vec<Metafield>>>;
+
+  fn list_metafields_page(
+    &self,
+    owner: MetafieldOwner,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<Metafield>>>;
+
+  fn get_metafield(&self, owner: MetafieldOwner, id: i64) -> ShopifyResult<Metafield>;
+
+  /// Creates a metafield, or updates it if a metafield with the same namespace and key exists.
+  fn create_metafield(
+    &self,
+    owner: MetafieldOwner,
+    metafield: &NewMetafield,
+  ) -> ShopifyResult<Metafield>;
+
+  fn update_metafield(
+    &self,
+    owner: MetafieldOwner,
+    id: i64,
+    metafield: &UpdateMetafield,
+  ) -> ShopifyResult<Metafield>;
+
+  fn delete_metafield(&self, owner: MetafieldOwner, id: i64) -> ShopifyResult<()>;
+}
+
+impl MetafieldApi for Client {
+  fn list_metafields(
+    &self,
+    owner: MetafieldOwner,
+    params: &GetMetafieldListParams,
+  ) -> ShopifyResult<Paginated<Vec<Metafield>>> {
+    shopify_wrap! {
+      pub struct Res {
+        metafields: Vec<Metafield>,
+      }
+    }
+
+    let path = format!("{}.json", owner.metafields_path());
+    let res: Paginated<Res> =
+      self.request_with_params_paginated(Method::GET, &path, params, std::convert::identity)?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_metafields_page(
+    &self,
+    owner: MetafieldOwner,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<Metafield>>> {
+    shopify_wrap! {
+      pub struct Res {
+        metafields: Vec<Metafield>,
+      }
+    }
+
+    let path = format!("{}.json", owner.metafields_path());
+    let res: Paginated<Res> =
+      self.request_with_params_paginated(Method::GET, &path, params, std::convert::identity)?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn get_metafield(&self, owner: MetafieldOwner, id: i64) -> ShopifyResult<Metafield> {
+    shopify_wrap! {
+      pub struct Res {
+        metafield: Metafield,
+      }
+    }
+
+    let path = format!("{}/{}.json", owner.metafields_path(), id);
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn create_metafield(
+    &self,
+    owner: MetafieldOwner,
+    metafield: &NewMetafield,
+  ) -> ShopifyResult<Metafield> {
+    shopify_wrap! {
+      pub struct Res {
+        metafield: Metafield,
+      }
+    }
+
+    let path = format!("{}.json", owner.metafields_path());
+    let res: Res = self.request(Method::POST, &path, move |b| {
+      b.json(&json!({ "metafield": metafield }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn update_metafield(
+    &self,
+    owner: MetafieldOwner,
+    id: i64,
+    metafield: &UpdateMetafield,
+  ) -> ShopifyResult<Metafield> {
+    shopify_wrap! {
+      pub struct Res {
+        metafield: Metafield,
+      }
+    }
+
+    let path = format!("{}/{}.json", owner.metafields_path(), id);
+    let res: Res = self.request(Method::PUT, &path, move |b| {
+      b.json(&json!({ "metafield": metafield }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn delete_metafield(&self, owner: MetafieldOwner, id: i64) -> ShopifyResult<()> {
+    let path = format!("{}/{}.json", owner.metafields_path(), id);
+    self.request_no_content(Method::DELETE, &path, std::convert::identity)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::order::Money;
+  use chrono::NaiveDate;
+
+  #[test]
+  fn test_metafields_path() {
+    assert_eq!(
+      MetafieldOwner::Shop.metafields_path(),
+      "/admin/api/2023-07/metafields"
+    );
+    assert_eq!(
+      MetafieldOwner::DraftOrder(1).metafields_path(),
+      "/admin/api/2023-07/draft_orders/1/metafields"
+    );
+  }
+
+  #[test]
+  fn test_metafield_value_roundtrip() {
+    let values = vec![
+      MetafieldValue::SingleLineTextField("8517.12".to_owned()),
+      MetafieldValue::NumberInteger(24),
+      MetafieldValue::Json(json!({ "erp_id": "A-1" })),
+      MetafieldValue::Date(NaiveDate::from_ymd_opt(2023, 7, 1).unwrap()),
+      MetafieldValue::Money(Money {
+        amount: Some("5.99".to_owned()),
+        currency_code: Some("CAD".to_owned()),
+      }),
+      MetafieldValue::List(
+        MetafieldType::ProductReference,
+        vec![
+          MetafieldValue::ProductReference("gid://shopify/Product/1".to_owned()),
+          MetafieldValue::ProductReference("gid://shopify/Product/2".to_owned()),
+        ],
+      ),
+      MetafieldValue::List(
+        MetafieldType::NumberInteger,
+        vec![
+          MetafieldValue::NumberInteger(1),
+          MetafieldValue::NumberInteger(2),
+        ],
+      ),
+    ];
+    for value in values {
+      let decoded = MetafieldValue::decode(
+        &value.metafield_type(),
+        &serde_json::Value::String(value.encode()),
+      )
+      .unwrap();
+      assert_eq!(decoded, value);
+    }
+  }
+
+  #[test]
+  fn test_deserialize_metafield() {
+    shopify_wrap! {
+      pub struct Res {
+        metafields: Vec<Metafield>,
+      }
+    }
+
+    let metafields = serde_json::from_str::<Res>(
+      r##"
+    {
+      "metafields": [
+        {
+          "id": 1069228939,
+          "namespace": "inventory",
+          "key": "warehouse",
+          "value": 25,
+          "description": null,
+          "owner_id": 632910392,
+          "created_at": "2023-07-11T18:33:22-04:00",
+          "updated_at": "2023-07-11T18:33:22-04:00",
+          "owner_resource": "product",
+          "type": "number_integer",
+          "admin_graphql_api_id": "gid://shopify/Metafield/1069228939"
+        },
+        {
+          "id": 1069228940,
+          "namespace": "custom",
+          "key": "related",
+          "value": "[\"gid://shopify/Product/1\"]",
+          "description": null,
+          "owner_id": 632910392,
+          "created_at": "2023-07-11T18:33:22-04:00",
+          "updated_at": "2023-07-11T18:33:22-04:00",
+          "owner_resource": "product",
+          "type": "list.product_reference",
+          "admin_graphql_api_id": "gid://shopify/Metafield/1069228940"
+        }
+      ]
+    }
+    "##,
+    )
+    .unwrap()
+    .into_inner();
+    assert_eq!(
+      metafields[0].typed_value().unwrap(),
+      MetafieldValue::NumberInteger(25)
+    );
+    assert_eq!(
+      metafields[1].type_,
+      MetafieldType::List(Box::new(MetafieldType::ProductReference))
+    );
+    assert_eq!(
+      metafields[1].typed_value().unwrap(),
+      MetafieldValue::List(
+        MetafieldType::ProductReference,
+        vec![MetafieldValue::ProductReference(
+          "gid://shopify/Product/1".to_owned()
+        )]
+      )
+    );
+  }
+
+  #[test]
+  #[ignore]
+  fn test_list_shop_metafields() {
+    let client = crate::client::get_test_client();
+    let page = client
+      .list_metafields(MetafieldOwner::Shop, &Default::default())
+      .unwrap();
+    println!("{:#?}", page);
+  }
+}
@@ -0,0 +1,329 @@
+use crate::order::Money;
+use crate::result::*;
+use crate::types::{DateTime, Utc, Value};
+use chrono::NaiveDate;
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+/// The resource a metafield is attached to.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum MetafieldOwner {
+  Shop,
+  Product(i64),
+  Variant(i64),
+  Order(i64),
+  Customer(i64),
+  Collection(i64),
+  Location(i64),
+  DraftOrder(i64),
+}
+
+impl MetafieldOwner {
+  /// The path of the owner's `metafields` collection, without the `.json` suffix.
+  pub(crate) fn metafields_path(&self) -> String {
+    let (resource, id) = match *self {
+      MetafieldOwner::Shop => return "/admin/api/2023-07/metafields".to_owned(),
+      MetafieldOwner::Product(id) => ("products", id),
+      MetafieldOwner::Variant(id) => ("variants", id),
+      MetafieldOwner::Order(id) => ("orders", id),
+      MetafieldOwner::Customer(id) => ("customers", id),
+      MetafieldOwner::Collection(id) => ("collections", id),
+      MetafieldOwner::Location(id) => ("locations", id),
+      MetafieldOwner::DraftOrder(id) => ("draft_orders", id),
+    };
+    format!("/admin/api/2023-07/{}/{}/metafields", resource, id)
+  }
+}
+
+/// The content type of a metafield, e.g. `single_line_text_field` or `list.product_reference`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetafieldType {
+  SingleLineTextField,
+  MultiLineTextField,
+  Boolean,
+  NumberInteger,
+  NumberDecimal,
+  Json,
+  Date,
+  Money,
+  Url,
+  ProductReference,
+  VariantReference,
+  CollectionReference,
+  CustomerReference,
+  PageReference,
+  FileReference,
+  MetaobjectReference,
+  List(Box<MetafieldType>),
+  /// Types without a typed representation, such as `rating` or `dimension`.
+  Other(String),
+}
+
+impl MetafieldType {
+  pub fn parse(name: &str) -> MetafieldType {
+    if let Some(item) = name.strip_prefix("list.") {
+      return MetafieldType::List(Box::new(MetafieldType::parse(item)));
+    }
+    match name {
+      "single_line_text_field" => MetafieldType::SingleLineTextField,
+      "multi_line_text_field" => MetafieldType::MultiLineTextField,
+      "boolean" => MetafieldType::Boolean,
+      "number_integer" => MetafieldType::NumberInteger,
+      "number_decimal" => MetafieldType::NumberDecimal,
+      "json" => MetafieldType::Json,
+      "date" => MetafieldType::Date,
+      "money" => MetafieldType::Money,
+      "url" => MetafieldType::Url,
+      "product_reference" => MetafieldType::ProductReference,
+      "variant_reference" => MetafieldType::VariantReference,
+      "collection_reference" => MetafieldType::CollectionReference,
+      "customer_reference" => MetafieldType::CustomerReference,
+      "page_reference" => MetafieldType::PageReference,
+      "file_reference" => MetafieldType::FileReference,
+      "metaobject_reference" => MetafieldType::MetaobjectReference,
+      other => MetafieldType::Other(other.to_owned()),
+    }
+  }
+
+  pub fn name(&self) -> String {
+    match *self {
+      MetafieldType::SingleLineTextField => "single_line_text_field",
+      MetafieldType::MultiLineTextField => "multi_line_text_field",
+      MetafieldType::Boolean => "boolean",
+      MetafieldType::NumberInteger => "number_integer",
+      MetafieldType::NumberDecimal => "number_decimal",
+      MetafieldType::Json => "json",
+      MetafieldType::Date => "date",
+      MetafieldType::Money => "money",
+      MetafieldType::Url => "url",
+      MetafieldType::ProductReference => "product_reference",
+      MetafieldType::VariantReference => "variant_reference",
+      MetafieldType::CollectionReference => "collection_reference",
+      MetafieldType::CustomerReference => "customer_reference",
+      MetafieldType::PageReference => "page_reference",
+      MetafieldType::FileReference => "file_reference",
+      MetafieldType::MetaobjectReference => "metaobject_reference",
+      MetafieldType::List(ref item) => return format!("list.{}", item.name()),
+      MetafieldType::Other(ref name) => return name.clone(),
+    }
+    .to_owned()
+  }
+}
+
+impl Serialize for MetafieldType {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&self.name())
+  }
+}
+
+impl<'de> Deserialize<'de> for MetafieldType {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    String::deserialize(deserializer).map(|name| MetafieldType::parse(&name))
+  }
+}
+
+/// A decoded metafield value.
+///
+/// References are global IDs, e.g. `gid://shopify/Product/1`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetafieldValue {
+  SingleLineTextField(String),
+  MultiLineTextField(String),
+  Boolean(bool),
+  NumberInteger(i64),
+  /// Kept as a string to preserve precision.
+  NumberDecimal(String),
+  Json(Value),
+  Date(NaiveDate),
+  Money(Money),
+  Url(String),
+  ProductReference(String),
+  VariantReference(String),
+  CollectionReference(String),
+  CustomerReference(String),
+  PageReference(String),
+  FileReference(String),
+  MetaobjectReference(String),
+  /// The item type and the items, which all have to be of that type.
+  List(MetafieldType, Vec<MetafieldValue>),
+  Other(MetafieldType, String),
+}
+
+impl MetafieldValue {
+  pub fn metafield_type(&self) -> MetafieldType {
+    match *self {
+      MetafieldValue::SingleLineTextField(_) => MetafieldType::SingleLineTextField,
+      MetafieldValue::MultiLineTextField(_) => MetafieldType::MultiLineTextField,
+      MetafieldValue::Boolean(_) => MetafieldType::Boolean,
+      MetafieldValue::NumberInteger(_) => MetafieldType::NumberInteger,
+      MetafieldValue::NumberDecimal(_) => MetafieldType::NumberDecimal,
+      MetafieldValue::Json(_) => MetafieldType::Json,
+      MetafieldValue::Date(_) => MetafieldType::Date,
+      MetafieldValue::Money(_) => MetafieldType::Money,
+      MetafieldValue::Url(_) => MetafieldType::Url,
+      MetafieldValue::ProductReference(_) => MetafieldType::ProductReference,
+      MetafieldValue::VariantReference(_) => MetafieldType::VariantReference,
+      MetafieldValue::CollectionReference(_) => MetafieldType::CollectionReference,
+      MetafieldValue::CustomerReference(_) => MetafieldType::CustomerReference,
+      MetafieldValue::PageReference(_) => MetafieldType::PageReference,
+      MetafieldValue::FileReference(_) => MetafieldType::FileReference,
+      MetafieldValue::MetaobjectReference(_) => MetafieldType::MetaobjectReference,
+      MetafieldValue::List(ref item, _) => MetafieldType::List(Box::new(item.clone())),
+      MetafieldValue::Other(ref type_, _) => type_.clone(),
+    }
+  }
+
+  /// Encodes the value as the string Shopify stores.
+  pub fn encode(&self) -> String {
+    match *self {
+      MetafieldValue::SingleLineTextField(ref v)
+      | MetafieldValue::MultiLineTextField(ref v)
+      | MetafieldValue::NumberDecimal(ref v)
+      | MetafieldValue::Url(ref v)
+      | MetafieldValue::ProductReference(ref v)
+      | MetafieldValue::VariantReference(ref v)
+      | MetafieldValue::CollectionReference(ref v)
+      | MetafieldValue::CustomerReference(ref v)
+      | MetafieldValue::PageReference(ref v)
+      | MetafieldValue::FileReference(ref v)
+      | MetafieldValue::MetaobjectReference(ref v)
+      | MetafieldValue::Other(_, ref v) => v.clone(),
+      MetafieldValue::Boolean(v) => v.to_string(),
+      MetafieldValue::NumberInteger(v) => v.to_string(),
+      MetafieldValue::Date(ref v) => v.format("%Y-%m-%d").to_string(),
+      MetafieldValue::Json(_) | MetafieldValue::Money(_) | MetafieldValue::List(..) => {
+        self.to_json().to_string()
+      }
+    }
+  }
+
+  /// Decodes `value` according to `type_`.
+  ///
+  /// The REST API returns some values, like integers and JSON, as JSON values rather than strings,
+  /// so both forms are accepted.
+  pub fn decode(type_: &MetafieldType, value: &Value) -> ShopifyResult<MetafieldValue> {
+    let invalid = || ShopifyError::InvalidMetafieldValue {
+      type_: type_.name(),
+      value: value.to_string(),
+    };
+    let text = match *value {
+      Value::String(ref v) => v.clone(),
+      ref v => v.to_string(),
+    };
+    let json = || match *value {
+      Value::String(ref v) => serde_json::from_str::<Value>(v).map_err(|_| invalid()),
+      ref v => Ok(v.clone()),
+    };
+
+    Ok(match *type_ {
+      MetafieldType::SingleLineTextField => MetafieldValue::SingleLineTextField(text),
+      MetafieldType::MultiLineTextField => MetafieldValue::MultiLineTextField(text),
+      MetafieldType::Boolean => MetafieldValue::Boolean(text.parse().map_err(|_| invalid())?),
+      MetafieldType::NumberInteger => {
+        MetafieldValue::NumberInteger(text.parse().map_err(|_| invalid())?)
+      }
+      MetafieldType::NumberDecimal => MetafieldValue::NumberDecimal(text),
+      MetafieldType::Json => MetafieldValue::Json(json()?),
+      MetafieldType::Date => {
+        MetafieldValue::Date(NaiveDate::parse_from_str(&text, "%Y-%m-%d").map_err(|_| invalid())?)
+      }
+      MetafieldType::Money => {
+        MetafieldValue::Money(serde_json::from_value(json()?).map_err(|_| invalid())?)
+      }
+      MetafieldType::Url => MetafieldValue::Url(text),
+      MetafieldType::ProductReference => MetafieldValue::ProductReference(text),
+      MetafieldType::VariantReference => MetafieldValue::VariantReference(text),
+      MetafieldType::CollectionReference => MetafieldValue::CollectionReference(text),
+      MetafieldType::CustomerReference => MetafieldValue::CustomerReference(text),
+      MetafieldType::PageReference => MetafieldValue::PageReference(text),
+      MetafieldType::FileReference => MetafieldValue::FileReference(text),
+      MetafieldType::MetaobjectReference => MetafieldValue::MetaobjectReference(text),
+      MetafieldType::List(ref item) => match json()? {
+        Value::Array(items) => MetafieldValue::List(
+          (**item).clone(),
+          items
+            .iter()
+            .map(|v| MetafieldValue::decode(item, v))
+            .collect::<ShopifyResult<_>>()?,
+        ),
+        _ => return Err(invalid()),
+      },
+      MetafieldType::Other(_) => MetafieldValue::Other(type_.clone(), text),
+    })
+  }
+
+  /// The value as a JSON value, e.g. an item of a `list.*` value.
+  pub(crate) fn to_json(&self) -> Value {
+    match *self {
+      MetafieldValue::Boolean(v) => Value::Bool(v),
+      MetafieldValue::NumberInteger(v) => Value::from(v),
+      MetafieldValue::Json(ref v) => v.clone(),
+      MetafieldValue::Money(ref v) => json!(v),
+      MetafieldValue::List(_, ref items) => {
+        Value::Array(items.iter().map(MetafieldValue::to_json).collect())
+      }
+      _ => Value::String(self.encode()),
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Metafield {
+  pub id: i64,
+  pub namespace: String,
+  pub key: String,
+  /// The raw value. Use [`Metafield::typed_value`] to decode it.
+  pub value: Value,
+  #[serde(rename = "type")]
+  pub type_: MetafieldType,
+  pub description: Option<String>,
+  pub owner_id: Option<i64>,
+  pub owner_resource: Option<String>,
+  pub created_at: Option<DateTime<Utc>>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl Metafield {
+  pub fn typed_value(&self) -> ShopifyResult<MetafieldValue> {
+    MetafieldValue::decode(&self.type_, &self.value)
+  }
+}
+
+#[derive(Debug, Serialize)]
+pub struct NewMetafield {
+  pub namespace: String,
+  pub key: String,
+  pub value: String,
+  #[serde(rename = "type")]
+  pub type_: MetafieldType,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+}
+
+impl NewMetafield {
+  pub fn new(namespace: &str, key: &str, value: &MetafieldValue) -> Self {
+    NewMetafield {
+      namespace: namespace.to_owned(),
+      key: key.to_owned(),
+      value: value.encode(),
+      type_: value.metafield_type(),
+      description: None,
+    }
+  }
+}
+
+#[derive(Debug, Serialize)]
+pub struct UpdateMetafield {
+  pub value: String,
+  #[serde(rename = "type")]
+  pub type_: MetafieldType,
+}
+
+impl UpdateMetafield {
+  pub fn new(value: &MetafieldValue) -> Self {
+    UpdateMetafield {
+      value: value.encode(),
+      type_: value.metafield_type(),
+    }
+  }
+}
@@ -0,0 +1,495 @@
+//! Metaobject definitions and entries.
+//!
+//! Metaobjects are only available in the GraphQL Admin API. Entry fields are mapped into a
+//! user-defined struct:
+//!
+//! ```no_run
+//! # use shopify::client::Client;
+//! use serde::{Deserialize, Serialize};
+//! use shopify::metaobject::*;
+//!
+//! #[derive(Debug, Serialize, Deserialize)]
+//! struct Designer {
+//!   name: String,
+//!   bio: Option<String>,
+//!   founded: Option<i64>,
+//! }
+//!
+//! # let client = Client::new("https://example.myshopify.com", "key", "password").unwrap();
+//! let designer = client
+//!   .upsert_metaobject(
+//!     "designer",
+//!     "jane-doe",
+//!     &Designer {
+//!       name: "Jane Doe".to_owned(),
+//!       bio: None,
+//!       founded: Some(2009),
+//!     },
+//!   )
+//!   .unwrap();
+//! println!("{}", designer.id);
+//! ```
+
+use crate::client::{Client, UserError};
+use crate::pagination::{GetPage, PageInfo, Paginated};
+use crate::result::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+mod types;
+pub use self::types::*;
+
+#[derive(Debug, Default)]
+pub struct GetMetaobjectListParams {
+  /// The page size. Defaults to 50.
+  pub limit: Option<i64>,
+}
+
+pub trait MetaobjectApi {
+  fn create_metaobject_definition(
+    &self,
+    definition: &NewMetaobjectDefinition,
+  ) -> ShopifyResult<MetaobjectDefinition>;
+
+  fn list_metaobject_definitions(
+    &self,
+    params: &GetMetaobjectListParams,
+  ) -> ShopifyResult<Paginated<Vec<MetaobjectDefinition>>>;
+
+  fn list_metaobject_definitions_page(
+    &self,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<MetaobjectDefinition>>>;
+
+  /// Deletes the definition and all of its entries.
+  fn delete_metaobject_definition(&self, id: &str) -> ShopifyResult<()>;
+
+  fn create_metaobject<T: Serialize + DeserializeOwned>(
+    &self,
+    type_: &str,
+    handle: Option<&str>,
+    fields: &T,
+  ) -> ShopifyResult<Metaobject<T>>;
+
+  /// Creates the entry with `handle`, or updates its fields if it exists.
+  fn upsert_metaobject<T: Serialize + DeserializeOwned>(
+    &self,
+    type_: &str,
+    handle: &str,
+    fields: &T,
+  ) -> ShopifyResult<Metaobject<T>>;
+
+  fn get_metaobject_by_handle<T: DeserializeOwned>(
+    &self,
+    type_: &str,
+    handle: &str,
+  ) -> ShopifyResult<Metaobject<T>>;
+
+  fn list_metaobjects<T: DeserializeOwned>(
+    &self,
+    type_: &str,
+    params: &GetMetaobjectListParams,
+  ) -> ShopifyResult<Paginated<Vec<Metaobject<T>>>>;
+
+  fn list_metaobjects_page<T: DeserializeOwned>(
+    &self,
+    type_: &str,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<Metaobject<T>>>>;
+
+  fn delete_metaobject(&self, id: &str) -> ShopifyResult<()>;
+}
+
+const DEFINITION_FIELDS: &str = "
+  id
+  type
+  name
+  description
+  displayNameKey
+  fieldDefinitions { key name description required type { name } }
+";
+
+const METAOBJECT_FIELDS: &str = "
+  id
+  handle
+  type
+  displayName
+  updatedAt
+  fields { key type value }
+";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Connection<T> {
+  nodes: Vec<T>,
+  page_info: PageInfo,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MutationPayload<T> {
+  #[serde(alias = "metaobjectDefinition", alias = "deletedId")]
+  metaobject: Option<T>,
+  user_errors: Vec<UserError>,
+}
+
+impl<T> MutationPayload<T> {
+  fn into_result(self) -> ShopifyResult<T> {
+    if !self.user_errors.is_empty() {
+      return Err(ShopifyError::UserErrors(self.user_errors));
+    }
+    self.metaobject.ok_or(ShopifyError::InvalidResponse)
+  }
+}
+
+impl MetaobjectApi for Client {
+  fn create_metaobject_definition(
+    &self,
+    definition: &NewMetaobjectDefinition,
+  ) -> ShopifyResult<MetaobjectDefinition> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Data {
+      metaobject_definition_create: MutationPayload<MetaobjectDefinition>,
+    }
+
+    let query = format!(
+      "mutation($definition: MetaobjectDefinitionCreateInput!) {{
+        metaobjectDefinitionCreate(definition: $definition) {{
+          metaobjectDefinition {{ {} }}
+          userErrors {{ field message code }}
+        }}
+      }}",
+      DEFINITION_FIELDS
+    );
+    let data: Data = self.graphql(&query, json!({ "definition": definition }))?;
+    data.metaobject_definition_create.into_result()
+  }
+
+  fn list_metaobject_definitions(
+    &self,
+    params: &GetMetaobjectListParams,
+  ) -> ShopifyResult<Paginated<Vec<MetaobjectDefinition>>> {
+    list_definitions(self, params.limit, None)
+  }
+
+  fn list_metaobject_definitions_page(
+    &self,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<MetaobjectDefinition>>> {
+    list_definitions(self, params.limit, Some(&params.page_info))
+  }
+
+  fn delete_metaobject_definition(&self, id: &str) -> ShopifyResult<()> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Data {
+      metaobject_definition_delete: MutationPayload<String>,
+    }
+
+    let data: Data = self.graphql(
+      "mutation($id: ID!) {
+        metaobjectDefinitionDelete(id: $id) {
+          deletedId
+          userErrors { field message code }
+        }
+      }",
+      json!({ "id": id }),
+    )?;
+    data.metaobject_definition_delete.into_result().map(|_| ())
+  }
+
+  fn create_metaobject<T: Serialize + DeserializeOwned>(
+    &self,
+    type_: &str,
+    handle: Option<&str>,
+    fields: &T,
+  ) -> ShopifyResult<Metaobject<T>> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Data {
+      metaobject_create: MutationPayload<RawMetaobject>,
+    }
+
+    let query = format!(
+      "mutation($metaobject: MetaobjectCreateInput!) {{
+        metaobjectCreate(metaobject: $metaobject) {{
+          metaobject {{ {} }}
+          userErrors {{ field message code }}
+        }}
+      }}",
+      METAOBJECT_FIELDS
+    );
+    let mut metaobject = json!({
+      "type": type_,
+      "fields": encode_fields(fields)?,
+    });
+    if let Some(handle) = handle {
+      metaobject["handle"] = json!(handle);
+    }
+    let data: Data = self.graphql(&query, json!({ "metaobject": metaobject }))?;
+    data.metaobject_create.into_result()?.decode()
+  }
+
+  fn upsert_metaobject<T: Serialize + DeserializeOwned>(
+    &self,
+    type_: &str,
+    handle: &str,
+    fields: &T,
+  ) -> ShopifyResult<Metaobject<T>> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Data {
+      metaobject_upsert: MutationPayload<RawMetaobject>,
+    }
+
+    let query = format!(
+      "mutation($handle: MetaobjectHandleInput!, $metaobject: MetaobjectUpsertInput!) {{
+        metaobjectUpsert(handle: $handle, metaobject: $metaobject) {{
+          metaobject {{ {} }}
+          userErrors {{ field message code }}
+        }}
+      }}",
+      METAOBJECT_FIELDS
+    );
+    let data: Data = self.graphql(
+      &query,
+      json!({
+        "handle": { "type": type_, "handle": handle },
+        "metaobject": { "fields": encode_fields(fields)? },
+      }),
+    )?;
+    data.metaobject_upsert.into_result()?.decode()
+  }
+
+  fn get_metaobject_by_handle<T: DeserializeOwned>(
+    &self,
+    type_: &str,
+    handle: &str,
+  ) -> ShopifyResult<Metaobject<T>> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Data {
+      metaobject_by_handle: Option<RawMetaobject>,
+    }
+
+    let query = format!(
+      "query($handle: MetaobjectHandleInput!) {{
+        metaobjectByHandle(handle: $handle) {{ {} }}
+      }}",
+      METAOBJECT_FIELDS
+    );
+    let data: Data = self.graphql(
+      &query,
+      json!({ "handle": { "type": type_, "handle": handle } }),
+    )?;
+    data
+      .metaobject_by_handle
+      .ok_or(ShopifyError::NotFound)?
+      .decode()
+  }
+
+  fn list_metaobjects<T: DeserializeOwned>(
+    &self,
+    type_: &str,
+    params: &GetMetaobjectListParams,
+  ) -> ShopifyResult<Paginated<Vec<Metaobject<T>>>> {
+    list_metaobjects(self, type_, params.limit, None)
+  }
+
+  fn list_metaobjects_page<T: DeserializeOwned>(
+    &self,
+    type_: &str,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<Metaobject<T>>>> {
+    list_metaobjects(self, type_, params.limit, Some(&params.page_info))
+  }
+
+  fn delete_metaobject(&self, id: &str) -> ShopifyResult<()> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Data {
+      metaobject_delete: MutationPayload<String>,
+    }
+
+    let data: Data = self.graphql(
+      "mutation($id: ID!) {
+        metaobjectDelete(id: $id) {
+          deletedId
+          userErrors { field message code }
+        }
+      }",
+      json!({ "id": id }),
+    )?;
+    data.metaobject_delete.into_result().map(|_| ())
+  }
+}
+
+fn list_definitions(
+  client: &Client,
+  limit: Option<i64>,
+  after: Option<&str>,
+) -> ShopifyResult<Paginated<Vec<MetaobjectDefinition>>> {
+  #[derive(Deserialize)]
+  #[serde(rename_all = "camelCase")]
+  struct Data {
+    metaobject_definitions: Connection<MetaobjectDefinition>,
+  }
+
+  let query = format!(
+    "query($first: Int!, $after: String) {{
+      metaobjectDefinitions(first: $first, after: $after) {{
+        nodes {{ {} }}
+        pageInfo {{ hasNextPage endCursor }}
+      }}
+    }}",
+    DEFINITION_FIELDS
+  );
+  let data: Data = client.graphql(
+    &query,
+    json!({ "first": limit.unwrap_or(50), "after": after }),
+  )?;
+  let connection = data.metaobject_definitions;
+  Ok(Paginated::from_page_info(
+    connection.nodes,
+    connection.page_info,
+  ))
+}
+
+fn list_metaobjects<T: DeserializeOwned>(
+  client: &Client,
+  type_: &str,
+  limit: Option<i64>,
+  after: Option<&str>,
+) -> ShopifyResult<Paginated<Vec<Metaobject<T>>>> {
+  #[derive(Deserialize)]
+  struct Data {
+    metaobjects: Connection<RawMetaobject>,
+  }
+
+  let query = format!(
+    "query($type: String!, $first: Int!, $after: String) {{
+      metaobjects(type: $type, first: $first, after: $after) {{
+        nodes {{ {} }}
+        pageInfo {{ hasNextPage endCursor }}
+      }}
+    }}",
+    METAOBJECT_FIELDS
+  );
+  let data: Data = client.graphql(
+    &query,
+    json!({ "type": type_, "first": limit.unwrap_or(50), "after": after }),
+  )?;
+  let connection = data.metaobjects;
+  let nodes = connection
+    .nodes
+    .into_iter()
+    .map(RawMetaobject::decode)
+    .collect::<ShopifyResult<Vec<_>>>()?;
+  Ok(Paginated::from_page_info(nodes, connection.page_info))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::metafield::MetafieldType;
+
+  #[derive(Debug, Serialize, Deserialize, PartialEq)]
+  struct Designer {
+    name: String,
+    bio: Option<String>,
+    founded: Option<i64>,
+    tags: Vec<String>,
+  }
+
+  #[test]
+  fn test_decode_metaobject() {
+    let raw: RawMetaobject = serde_json::from_str(
+      r##"
+    {
+      "id": "gid://shopify/Metaobject/1",
+      "handle": "jane-doe",
+      "type": "designer",
+      "displayName": "Jane Doe",
+      "updatedAt": "2023-07-11T18:33:22Z",
+      "fields": [
+        { "key": "name", "type": "single_line_text_field", "value": "Jane Doe" },
+        { "key": "bio", "type": "multi_line_text_field", "value": null },
+        { "key": "founded", "type": "number_integer", "value": "2009" },
+        { "key": "tags", "type": "list.single_line_text_field", "value": "[\"a\",\"b\"]" }
+      ]
+    }
+    "##,
+    )
+    .unwrap();
+    let metaobject: Metaobject<Designer> = raw.decode().unwrap();
+    assert_eq!(metaobject.handle, "jane-doe");
+    assert_eq!(
+      metaobject.fields,
+      Designer {
+        name: "Jane Doe".to_owned(),
+        bio: None,
+        founded: Some(2009),
+        tags: vec!["a".to_owned(), "b".to_owned()],
+      }
+    );
+  }
+
+  #[test]
+  fn test_encode_fields() {
+    let fields = encode_fields(&Designer {
+      name: "Jane Doe".to_owned(),
+      bio: None,
+      founded: Some(2009),
+      tags: vec!["a".to_owned()],
+    })
+    .unwrap();
+    assert_eq!(
+      fields,
+      vec![
+        json!({ "key": "founded", "value": "2009" }),
+        json!({ "key": "name", "value": "Jane Doe" }),
+        json!({ "key": "tags", "value": "[\"a\"]" }),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_serialize_new_metaobject_definition() {
+    let definition = NewMetaobjectDefinition {
+      type_: "designer".to_owned(),
+      name: "Designer".to_owned(),
+      field_definitions: vec![
+        NewMetaobjectFieldDefinition::new("name", MetafieldType::SingleLineTextField).required(),
+        NewMetaobjectFieldDefinition::new(
+          "tags",
+          MetafieldType::List(Box::new(MetafieldType::SingleLineTextField)),
+        ),
+      ],
+      description: None,
+      display_name_key: Some("name".to_owned()),
+    };
+    assert_eq!(
+      serde_json::to_value(definition).unwrap(),
+      json!({
+        "type": "designer",
+        "name": "Designer",
+        "fieldDefinitions": [
+          { "key": "name", "type": "single_line_text_field", "required": true },
+          { "key": "tags", "type": "list.single_line_text_field" }
+        ],
+        "displayNameKey": "name"
+      })
+    );
+  }
+
+  #[test]
+  #[ignore]
+  fn test_list_metaobject_definitions() {
+    let client = crate::client::get_test_client();
+    let page = client
+      .list_metaobject_definitions(&Default::default())
+      .unwrap();
+    println!("{:#?}", page);
+  }
+}
@@ -0,0 +1,170 @@
+use crate::metafield::{MetafieldType, MetafieldValue};
+use crate::result::*;
+use crate::types::{DateTime, Utc, Value};
+use serde::de::{Deserialize, DeserializeOwned, Deserializer};
+use serde::Serialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetaobjectFieldDefinition {
+  pub key: String,
+  pub name: Option<String>,
+  pub description: Option<String>,
+  pub required: bool,
+  #[serde(rename = "type", deserialize_with = "deserialize_type_name")]
+  pub type_: MetafieldType,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetaobjectDefinition {
+  /// The global ID, e.g. `gid://shopify/MetaobjectDefinition/1`.
+  pub id: String,
+  #[serde(rename = "type")]
+  pub type_: String,
+  pub name: String,
+  pub description: Option<String>,
+  pub display_name_key: Option<String>,
+  pub field_definitions: Vec<MetaobjectFieldDefinition>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewMetaobjectFieldDefinition {
+  pub key: String,
+  #[serde(rename = "type")]
+  pub type_: MetafieldType,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub required: Option<bool>,
+}
+
+impl NewMetaobjectFieldDefinition {
+  pub fn new(key: &str, type_: MetafieldType) -> Self {
+    NewMetaobjectFieldDefinition {
+      key: key.to_owned(),
+      type_,
+      name: None,
+      description: None,
+      required: None,
+    }
+  }
+
+  pub fn required(self) -> Self {
+    Self {
+      required: Some(true),
+      ..self
+    }
+  }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewMetaobjectDefinition {
+  #[serde(rename = "type")]
+  pub type_: String,
+  pub name: String,
+  pub field_definitions: Vec<NewMetaobjectFieldDefinition>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+  /// The key of the field used as the display name of entries.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub display_name_key: Option<String>,
+}
+
+/// A metaobject entry with its fields mapped into `T`.
+///
+/// Field values are decoded according to their type before being deserialized into `T`:
+/// integers and booleans become JSON numbers and booleans, `json`, `money` and `list.*` fields
+/// become JSON values, and everything else, including decimals, stays a string. Empty fields
+/// are `null`.
+#[derive(Debug)]
+pub struct Metaobject<T> {
+  /// The global ID, e.g. `gid://shopify/Metaobject/1`.
+  pub id: String,
+  pub handle: String,
+  pub type_: String,
+  pub display_name: Option<String>,
+  pub updated_at: Option<DateTime<Utc>>,
+  pub fields: T,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RawMetaobject {
+  id: String,
+  handle: String,
+  #[serde(rename = "type")]
+  type_: String,
+  display_name: Option<String>,
+  updated_at: Option<DateTime<Utc>>,
+  fields: Vec<RawMetaobjectField>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct RawMetaobjectField {
+  key: String,
+  #[serde(rename = "type")]
+  type_: MetafieldType,
+  value: Option<String>,
+}
+
+impl RawMetaobject {
+  pub(crate) fn decode<T: DeserializeOwned>(self) -> ShopifyResult<Metaobject<T>> {
+    let mut fields = serde_json::Map::new();
+    for field in self.fields {
+      let value = match field.value {
+        Some(value) => MetafieldValue::decode(&field.type_, &Value::String(value))?.to_json(),
+        None => Value::Null,
+      };
+      fields.insert(field.key, value);
+    }
+    Ok(Metaobject {
+      id: self.id,
+      handle: self.handle,
+      type_: self.type_,
+      display_name: self.display_name,
+      updated_at: self.updated_at,
+      fields: serde_json::from_value(Value::Object(fields))?,
+    })
+  }
+}
+
+/// The `{ key, value }` field inputs of a metaobject mutation.
+///
+/// `null` fields are left out, so they keep their current value on update.
+pub(crate) fn encode_fields<T: Serialize>(fields: &T) -> ShopifyResult<Vec<Value>> {
+  match serde_json::to_value(fields)? {
+    Value::Object(map) => Ok(
+      map
+        .into_iter()
+        .filter(|(_, v)| !v.is_null())
+        .map(|(key, value)| {
+          let value = match value {
+            Value::String(v) => v,
+            v => v.to_string(),
+          };
+          json!({ "key": key, "value": value })
+        })
+        .collect(),
+    ),
+    _ => Err(ShopifyError::InvalidMetafieldValue {
+      type_: "metaobject".to_owned(),
+      value: "fields must serialize to a JSON object".to_owned(),
+    }),
+  }
+}
+
+fn deserialize_type_name<'de, D>(deserializer: D) -> Result<MetafieldType, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  #[derive(Deserialize)]
+  struct TypeName {
+    name: MetafieldType,
+  }
+  TypeName::deserialize(deserializer).map(|t| t.name)
+}
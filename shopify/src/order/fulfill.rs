@@ -0,0 +1,414 @@
+use crate::client::Client;
+use crate::order::{
+  CreateFulfillmentRequest, Fulfillment, FulfillmentOrder, FulfillmentOrderAction,
+  FulfillmentOrderLineItems, FulfillmentOrderStatus, LineItemsByFulfillmentOrder,
+  MoveFulfillmentOrderRequest, Order, OrderApi, TrackingInfo,
+};
+use crate::result::*;
+use std::collections::{BTreeMap, HashMap};
+
+/// Identifies the order line item to fulfill.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FulfillItemRef {
+  LineItem(i64),
+  /// Matches every line item of the order with this SKU.
+  Sku(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct FulfillItem {
+  pub item: FulfillItemRef,
+  pub quantity: i64,
+  /// The location the items are shipped from.
+  pub location_id: i64,
+}
+
+#[derive(Debug, Default)]
+pub struct FulfillRequest {
+  pub items: Vec<FulfillItem>,
+  /// Applied to every created fulfillment.
+  pub tracking_info: Option<TrackingInfo>,
+  pub notify_customer: Option<bool>,
+}
+
+/// A quantity of a fulfillment order line item to fulfill from a location.
+#[derive(Debug, Clone, PartialEq)]
+struct Allocation {
+  fulfillment_order_id: i64,
+  line_item_id: i64,
+  location_id: i64,
+  quantity: i64,
+}
+
+/// Fulfills the requested items from the requested locations.
+///
+/// Items are taken from the open fulfillment orders of `order`, splitting a requested quantity
+/// across fulfillment orders if necessary. Fulfillment orders assigned to another location are
+/// moved to the requested location first. One fulfillment is created per location.
+pub(crate) fn fulfill(
+  client: &Client,
+  order: &Order,
+  request: &FulfillRequest,
+) -> ShopifyResult<Vec<Fulfillment>> {
+  let items = request
+    .items
+    .iter()
+    .map(|item| {
+      let line_item_ids: Vec<i64> = match item.item {
+        FulfillItemRef::LineItem(id) => vec![id],
+        FulfillItemRef::Sku(ref sku) => order
+          .line_items
+          .iter()
+          .filter(|li| li.sku.as_ref() == Some(sku))
+          .map(|li| li.id)
+          .collect(),
+      };
+      if line_item_ids.is_empty() {
+        return Err(ShopifyError::LineItemNotFound(describe(&item.item)));
+      }
+      Ok((item, line_item_ids))
+    })
+    .collect::<ShopifyResult<Vec<_>>>()?;
+
+  let fulfillment_orders = client.get_fulfillment_orders(order.id)?;
+  let allocations = allocate(&items, &fulfillment_orders)?;
+
+  let mut progress = Progress::default();
+  let result = execute(
+    client,
+    request,
+    &fulfillment_orders,
+    &allocations,
+    &mut progress,
+  );
+  progress.finish(result)
+}
+
+/// What has already been done on Shopify, reported with the error if a later step fails.
+#[derive(Debug, Default)]
+struct Progress {
+  moved_fulfillment_orders: Vec<FulfillmentOrder>,
+  fulfillments: Vec<Fulfillment>,
+}
+
+impl Progress {
+  fn finish(self, result: ShopifyResult<()>) -> ShopifyResult<Vec<Fulfillment>> {
+    match result {
+      Ok(()) => Ok(self.fulfillments),
+      Err(err) if self.moved_fulfillment_orders.is_empty() && self.fulfillments.is_empty() => {
+        Err(err)
+      }
+      Err(err) => Err(ShopifyError::PartialFulfillment {
+        fulfillments: self.fulfillments,
+        moved_fulfillment_orders: self.moved_fulfillment_orders,
+        source: Box::new(err),
+      }),
+    }
+  }
+}
+
+fn execute(
+  client: &Client,
+  request: &FulfillRequest,
+  fulfillment_orders: &[FulfillmentOrder],
+  allocations: &[Allocation],
+  progress: &mut Progress,
+) -> ShopifyResult<()> {
+  // Move fulfillment orders that are assigned to other locations.
+  let mut by_fulfillment_order: BTreeMap<(i64, i64), Vec<&Allocation>> = BTreeMap::new();
+  for allocation in allocations {
+    by_fulfillment_order
+      .entry((allocation.fulfillment_order_id, allocation.location_id))
+      .or_default()
+      .push(allocation);
+  }
+
+  let mut by_location: BTreeMap<i64, Vec<LineItemsByFulfillmentOrder>> = BTreeMap::new();
+  for ((fulfillment_order_id, location_id), allocations) in by_fulfillment_order {
+    let fulfillment_order = fulfillment_orders
+      .iter()
+      .find(|fo| fo.id == fulfillment_order_id)
+      .ok_or(ShopifyError::InvalidResponse)?;
+    let fulfillment_order = if fulfillment_order.assigned_location_id == Some(location_id) {
+      fulfillment_order_line_items(fulfillment_order, &allocations)
+        .map(|items| (fulfillment_order.id, items))
+    } else {
+      if !fulfillment_order.supports(FulfillmentOrderAction::Move) {
+        return Err(ShopifyError::UnsupportedFulfillmentOrderAction {
+          id: fulfillment_order.id,
+          action: FulfillmentOrderAction::Move.as_str(),
+        });
+      }
+      let moved = client
+        .move_fulfillment_order(
+          fulfillment_order.id,
+          &MoveFulfillmentOrderRequest {
+            new_location_id: location_id,
+            fulfillment_order_line_items: Some(fulfillment_order_line_items(
+              fulfillment_order,
+              &allocations,
+            )?),
+          },
+        )?
+        .moved_fulfillment_order;
+      let items = fulfillment_order_line_items(&moved, &allocations).map(|items| (moved.id, items));
+      progress.moved_fulfillment_orders.push(moved);
+      items
+    };
+    let (fulfillment_order_id, fulfillment_order_line_items) = fulfillment_order?;
+    by_location
+      .entry(location_id)
+      .or_default()
+      .push(LineItemsByFulfillmentOrder {
+        fulfillment_order_id,
+        fulfillment_order_line_items,
+      });
+  }
+
+  for line_items_by_fulfillment_order in by_location.into_values() {
+    let fulfillment = client.create_fulfillment(&CreateFulfillmentRequest {
+      line_items_by_fulfillment_order,
+      notify_customer: request.notify_customer,
+      tracking_info: request.tracking_info.clone(),
+    })?;
+    progress.fulfillments.push(fulfillment);
+  }
+  Ok(())
+}
+
+/// Maps allocations to the line items of `fulfillment_order`, which may be a moved copy of the
+/// fulfillment order the allocations were made from.
+fn fulfillment_order_line_items(
+  fulfillment_order: &FulfillmentOrder,
+  allocations: &[&Allocation],
+) -> ShopifyResult<Vec<FulfillmentOrderLineItems>> {
+  allocations
+    .iter()
+    .map(|allocation| {
+      fulfillment_order
+        .line_items
+        .iter()
+        .find(|li| li.line_item_id == allocation.line_item_id)
+        .map(|li| FulfillmentOrderLineItems {
+          id: li.id,
+          quantity: allocation.quantity,
+        })
+        .ok_or(ShopifyError::InvalidResponse)
+    })
+    .collect()
+}
+
+/// Distributes the requested quantities over the fulfillable quantities of the fulfillment orders.
+fn allocate(
+  items: &[(&FulfillItem, Vec<i64>)],
+  fulfillment_orders: &[FulfillmentOrder],
+) -> ShopifyResult<Vec<Allocation>> {
+  if let Some((item, _)) = items.iter().find(|(item, _)| item.quantity <= 0) {
+    return Err(ShopifyError::InvalidFulfillQuantity {
+      item: describe(&item.item),
+      quantity: item.quantity,
+    });
+  }
+
+  let mut available: HashMap<i64, i64> = HashMap::new();
+  let mut allocations: Vec<Allocation> = vec![];
+
+  for (item, line_item_ids) in items {
+    let mut remaining = item.quantity;
+    let mut candidates: Vec<&FulfillmentOrder> = fulfillment_orders
+      .iter()
+      .filter(|fo| {
+        (fo.status == FulfillmentOrderStatus::Open
+          || fo.status == FulfillmentOrderStatus::InProgress)
+          && fo.supports(FulfillmentOrderAction::CreateFulfillment)
+      })
+      .collect();
+    // Prefer fulfillment orders that don't have to be moved.
+    candidates.sort_by_key(|fo| fo.assigned_location_id != Some(item.location_id));
+
+    for fo in candidates {
+      for li in fo
+        .line_items
+        .iter()
+        .filter(|li| line_item_ids.contains(&li.line_item_id))
+      {
+        if remaining == 0 {
+          break;
+        }
+        let left = available
+          .entry(li.id)
+          .or_insert_with(|| li.fulfillable_quantity.unwrap_or(0));
+        let quantity = remaining.min(*left);
+        if quantity == 0 {
+          continue;
+        }
+        *left -= quantity;
+        remaining -= quantity;
+
+        match allocations.iter_mut().find(|a| {
+          a.fulfillment_order_id == fo.id
+            && a.line_item_id == li.line_item_id
+            && a.location_id == item.location_id
+        }) {
+          Some(allocation) => allocation.quantity += quantity,
+          None => allocations.push(Allocation {
+            fulfillment_order_id: fo.id,
+            line_item_id: li.line_item_id,
+            location_id: item.location_id,
+            quantity,
+          }),
+        }
+      }
+    }
+
+    if remaining > 0 {
+      return Err(ShopifyError::InsufficientFulfillableQuantity {
+        item: describe(&item.item),
+        missing: remaining,
+      });
+    }
+  }
+
+  Ok(allocations)
+}
+
+fn describe(item: &FulfillItemRef) -> String {
+  match *item {
+    FulfillItemRef::LineItem(id) => format!("line item {}", id),
+    FulfillItemRef::Sku(ref sku) => format!("sku '{}'", sku),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn fulfillment_orders() -> Vec<FulfillmentOrder> {
+    serde_json::from_str(
+      r##"
+    [
+      {
+        "id": 1,
+        "assigned_location_id": 100,
+        "status": "open",
+        "supported_actions": ["create_fulfillment", "move"],
+        "line_items": [
+          { "id": 11, "line_item_id": 1000, "fulfillable_quantity": 2 },
+          { "id": 12, "line_item_id": 2000, "fulfillable_quantity": 1 }
+        ]
+      },
+      {
+        "id": 2,
+        "assigned_location_id": 200,
+        "status": "open",
+        "supported_actions": ["create_fulfillment", "move"],
+        "line_items": [
+          { "id": 21, "line_item_id": 1000, "fulfillable_quantity": 3 }
+        ]
+      },
+      {
+        "id": 3,
+        "assigned_location_id": 100,
+        "status": "closed",
+        "supported_actions": [],
+        "line_items": [
+          { "id": 31, "line_item_id": 1000, "fulfillable_quantity": 5 }
+        ]
+      }
+    ]
+    "##,
+    )
+    .unwrap()
+  }
+
+  fn item(line_item_id: i64, quantity: i64, location_id: i64) -> FulfillItem {
+    FulfillItem {
+      item: FulfillItemRef::LineItem(line_item_id),
+      quantity,
+      location_id,
+    }
+  }
+
+  #[test]
+  fn test_allocate_splits_across_fulfillment_orders() {
+    let item = item(1000, 4, 200);
+    let allocations = allocate(&[(&item, vec![1000])], &fulfillment_orders()).unwrap();
+    assert_eq!(
+      allocations,
+      vec![
+        Allocation {
+          fulfillment_order_id: 2,
+          line_item_id: 1000,
+          location_id: 200,
+          quantity: 3,
+        },
+        Allocation {
+          fulfillment_order_id: 1,
+          line_item_id: 1000,
+          location_id: 200,
+          quantity: 1,
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn test_allocate_insufficient_quantity() {
+    let first = item(1000, 4, 100);
+    let second = item(1000, 2, 100);
+    let err = allocate(
+      &[(&first, vec![1000]), (&second, vec![1000])],
+      &fulfillment_orders(),
+    )
+    .unwrap_err();
+    assert!(matches!(
+      err,
+      ShopifyError::InsufficientFulfillableQuantity { missing: 1, .. }
+    ));
+  }
+
+  #[test]
+  fn test_allocate_invalid_quantity() {
+    let valid = item(1000, 1, 100);
+    for quantity in &[0, -1] {
+      let invalid = item(2000, *quantity, 100);
+      let err = allocate(
+        &[(&valid, vec![1000]), (&invalid, vec![2000])],
+        &fulfillment_orders(),
+      )
+      .unwrap_err();
+      match err {
+        ShopifyError::InvalidFulfillQuantity { item, quantity: q } => {
+          assert_eq!(item, "line item 2000");
+          assert_eq!(q, *quantity);
+        }
+        other => panic!("unexpected error: {:?}", other),
+      }
+    }
+  }
+
+  #[test]
+  fn test_progress_reported_with_error() {
+    let progress = Progress::default();
+    assert!(matches!(
+      progress.finish(Err(ShopifyError::NotFound)),
+      Err(ShopifyError::NotFound)
+    ));
+
+    let progress = Progress {
+      moved_fulfillment_orders: fulfillment_orders().into_iter().take(1).collect(),
+      fulfillments: vec![],
+    };
+    match progress.finish(Err(ShopifyError::NotFound)) {
+      Err(ShopifyError::PartialFulfillment {
+        moved_fulfillment_orders,
+        source,
+        ..
+      }) => {
+        assert_eq!(moved_fulfillment_orders[0].id, 1);
+        assert!(matches!(*source, ShopifyError::NotFound));
+      }
+      other => panic!("unexpected result: {:?}", other),
+    }
+  }
+}
@@ -1,4 +1,6 @@
 use crate::order::fulfillment_order::FulfillmentOrderLineItems;
+use crate::result::*;
+use crate::tracking;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Fulfillment {
@@ -58,12 +60,21 @@ pub struct Set {
   pub presentment_money: Option<Money>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Money {
   pub amount: Option<String>,
   pub currency_code: Option<String>,
 }
 
+impl Money {
+  pub fn new(amount: &str, currency_code: &str) -> Self {
+    Money {
+      amount: Some(amount.to_owned()),
+      currency_code: Some(currency_code.to_owned()),
+    }
+  }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Receipt {}
 
@@ -80,10 +91,53 @@ pub struct LineItemsByFulfillmentOrder {
   pub fulfillment_order_line_items: Vec<FulfillmentOrderLineItems>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct TrackingInfo {
   pub company: String,
   pub number: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   pub url: Option<String>,
+  /// Additional tracking numbers, for shipments split into multiple packages.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub numbers: Vec<String>,
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub urls: Vec<String>,
+}
+
+impl TrackingInfo {
+  /// Creates tracking info with the carrier name normalized to Shopify's supported companies,
+  /// and the tracking URL filled in for carriers known to [`tracking`].
+  ///
+  /// Fails if `number` doesn't match the formats of a known carrier.
+  pub fn new(carrier: &str, number: &str) -> ShopifyResult<Self> {
+    let company = tracking::normalize_company(carrier);
+    if let Some(known) = tracking::Carrier::from_name(carrier) {
+      if !known.is_valid_tracking_number(number) {
+        return Err(ShopifyError::InvalidTrackingNumber {
+          company,
+          number: number.to_owned(),
+        });
+      }
+    }
+    Ok(TrackingInfo {
+      company,
+      number: number.trim().to_owned(),
+      url: tracking::tracking_url(carrier, number),
+      ..Default::default()
+    })
+  }
+
+  /// Sets the tracking URLs from a template (see [`tracking::tracking_url_from_template`]), for
+  /// carriers that Shopify doesn't link.
+  pub fn url_template(self, template: &str) -> Self {
+    TrackingInfo {
+      url: Some(tracking::tracking_url_from_template(template, &self.number)),
+      urls: self
+        .numbers
+        .iter()
+        .map(|number| tracking::tracking_url_from_template(template, number))
+        .collect(),
+      ..self
+    }
+  }
 }
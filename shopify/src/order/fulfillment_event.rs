@@ -0,0 +1,191 @@
+use crate::client::{Client, Method};
+use crate::result::*;
+use crate::types::{DateTime, Utc};
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FulfillmentEventStatus {
+  LabelPrinted,
+  LabelPurchased,
+  Confirmed,
+  ReadyForPickup,
+  CarrierPickedUp,
+  InTransit,
+  OutForDelivery,
+  AttemptedDelivery,
+  Delayed,
+  Delivered,
+  /// Picked up by the customer.
+  PickedUp,
+  Failure,
+  #[serde(other)]
+  Other,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FulfillmentEvent {
+  pub id: i64,
+  pub fulfillment_id: i64,
+  pub order_id: i64,
+  pub shop_id: Option<i64>,
+  pub status: FulfillmentEventStatus,
+  pub message: Option<String>,
+  pub happened_at: Option<DateTime<Utc>>,
+  pub estimated_delivery_at: Option<DateTime<Utc>>,
+  pub address1: Option<String>,
+  pub city: Option<String>,
+  pub province: Option<String>,
+  pub country: Option<String>,
+  pub zip: Option<String>,
+  pub latitude: Option<f64>,
+  pub longitude: Option<f64>,
+  pub created_at: Option<DateTime<Utc>>,
+  pub updated_at: Option<DateTime<Utc>>,
+  pub admin_graphql_api_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NewFulfillmentEvent {
+  pub status: FulfillmentEventStatus,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub message: Option<String>,
+  /// When the event occurred. Defaults to the time the event is created.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub happened_at: Option<DateTime<Utc>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub estimated_delivery_at: Option<DateTime<Utc>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub address1: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub city: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub province: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub country: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub zip: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub latitude: Option<f64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub longitude: Option<f64>,
+}
+
+impl NewFulfillmentEvent {
+  pub fn new(status: FulfillmentEventStatus) -> Self {
+    NewFulfillmentEvent {
+      status,
+      message: None,
+      happened_at: None,
+      estimated_delivery_at: None,
+      address1: None,
+      city: None,
+      province: None,
+      country: None,
+      zip: None,
+      latitude: None,
+      longitude: None,
+    }
+  }
+}
+
+pub trait FulfillmentEventApi {
+  fn get_fulfillment_events(
+    &self,
+    order_id: i64,
+    fulfillment_id: i64,
+  ) -> ShopifyResult<Vec<FulfillmentEvent>>;
+
+  fn get_fulfillment_event(
+    &self,
+    order_id: i64,
+    fulfillment_id: i64,
+    event_id: i64,
+  ) -> ShopifyResult<FulfillmentEvent>;
+
+  fn create_fulfillment_event(
+    &self,
+    order_id: i64,
+    fulfillment_id: i64,
+    event: &NewFulfillmentEvent,
+  ) -> ShopifyResult<FulfillmentEvent>;
+
+  fn delete_fulfillment_event(
+    &self,
+    order_id: i64,
+    fulfillment_id: i64,
+    event_id: i64,
+  ) -> ShopifyResult<()>;
+}
+
+impl FulfillmentEventApi for Client {
+  fn get_fulfillment_events(
+    &self,
+    order_id: i64,
+    fulfillment_id: i64,
+  ) -> ShopifyResult<Vec<FulfillmentEvent>> {
+    shopify_wrap! {
+      pub struct Res {
+        fulfillment_events: Vec<FulfillmentEvent>,
+      }
+    }
+    let path = format!(
+      "/admin/api/2023-07/orders/{}/fulfillments/{}/events.json",
+      order_id, fulfillment_id
+    );
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn get_fulfillment_event(
+    &self,
+    order_id: i64,
+    fulfillment_id: i64,
+    event_id: i64,
+  ) -> ShopifyResult<FulfillmentEvent> {
+    shopify_wrap! {
+      pub struct Res {
+        fulfillment_event: FulfillmentEvent,
+      }
+    }
+    let path = format!(
+      "/admin/api/2023-07/orders/{}/fulfillments/{}/events/{}.json",
+      order_id, fulfillment_id, event_id
+    );
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn create_fulfillment_event(
+    &self,
+    order_id: i64,
+    fulfillment_id: i64,
+    event: &NewFulfillmentEvent,
+  ) -> ShopifyResult<FulfillmentEvent> {
+    shopify_wrap! {
+      pub struct Res {
+        fulfillment_event: FulfillmentEvent,
+      }
+    }
+    let path = format!(
+      "/admin/api/2023-07/orders/{}/fulfillments/{}/events.json",
+      order_id, fulfillment_id
+    );
+    let res: Res = self.request(Method::POST, &path, move |b| {
+      b.json(&json!({ "event": event }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn delete_fulfillment_event(
+    &self,
+    order_id: i64,
+    fulfillment_id: i64,
+    event_id: i64,
+  ) -> ShopifyResult<()> {
+    let path = format!(
+      "/admin/api/2023-07/orders/{}/fulfillments/{}/events/{}.json",
+      order_id, fulfillment_id, event_id
+    );
+    self.request_no_content(Method::DELETE, &path, std::convert::identity)
+  }
+}
@@ -1,3 +1,7 @@
+use crate::client::{Client, Method};
+use crate::result::*;
+use crate::types::{DateTime, Utc};
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct FulfillmentOrder {
   pub assigned_location: Option<AssignedLocation>,
@@ -32,6 +36,76 @@ pub enum FulfillmentOrderStatus {
   Closed,
 }
 
+impl FulfillmentOrder {
+  /// Whether `action` is listed in `supported_actions`.
+  ///
+  /// Returns `true` if `supported_actions` was not returned by Shopify.
+  pub fn supports(&self, action: FulfillmentOrderAction) -> bool {
+    self
+      .supported_actions
+      .as_ref()
+      .map(|actions| actions.iter().any(|a| a == action.as_str()))
+      .unwrap_or(true)
+  }
+
+  fn ensure_supports(&self, action: FulfillmentOrderAction) -> ShopifyResult<()> {
+    if self.supports(action) {
+      Ok(())
+    } else {
+      Err(ShopifyError::UnsupportedFulfillmentOrderAction {
+        id: self.id,
+        action: action.as_str(),
+      })
+    }
+  }
+
+  /// `close` and `reschedule` are not listed in `supported_actions`, they depend on the status.
+  fn ensure_status(
+    &self,
+    status: FulfillmentOrderStatus,
+    action: &'static str,
+  ) -> ShopifyResult<()> {
+    if self.status == status {
+      Ok(())
+    } else {
+      Err(ShopifyError::UnsupportedFulfillmentOrderAction {
+        id: self.id,
+        action,
+      })
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FulfillmentOrderAction {
+  CreateFulfillment,
+  RequestFulfillment,
+  CancelFulfillmentOrder,
+  RequestCancellation,
+  Move,
+  Hold,
+  ReleaseHold,
+  MarkAsOpen,
+  External,
+}
+
+impl FulfillmentOrderAction {
+  pub fn as_str(&self) -> &'static str {
+    match *self {
+      FulfillmentOrderAction::CreateFulfillment => "create_fulfillment",
+      FulfillmentOrderAction::RequestFulfillment => "request_fulfillment",
+      FulfillmentOrderAction::CancelFulfillmentOrder => "cancel_fulfillment_order",
+      FulfillmentOrderAction::RequestCancellation => "request_cancellation",
+      FulfillmentOrderAction::Move => "move",
+      FulfillmentOrderAction::Hold => "hold",
+      FulfillmentOrderAction::ReleaseHold => "release_hold",
+      FulfillmentOrderAction::MarkAsOpen => "mark_as_open",
+      FulfillmentOrderAction::External => "external",
+    }
+  }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AssignedLocation {
   pub address1: Option<String>,
@@ -82,3 +156,228 @@ pub struct MoveFulfillmentOrderResponse {
   pub original_fulfillment_order: FulfillmentOrder,
   pub moved_fulfillment_order: FulfillmentOrder,
 }
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FulfillmentHoldReason {
+  AwaitingPayment,
+  HighRiskOfFraud,
+  IncorrectAddress,
+  InventoryOutOfStock,
+  UnknownDeliveryDate,
+  Other,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FulfillmentHold {
+  pub reason: FulfillmentHoldReason,
+  /// Additional information about the hold. Required when `reason` is `other`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub reason_notes: Option<String>,
+  /// Whether the merchant receives a notification about the hold.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub notify_merchant: Option<bool>,
+  /// The line items to hold. Holds the whole fulfillment order when `None`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub fulfillment_order_line_items: Option<Vec<FulfillmentOrderLineItems>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CancelFulfillmentOrderResponse {
+  pub fulfillment_order: FulfillmentOrder,
+  pub replacement_fulfillment_order: Option<FulfillmentOrder>,
+}
+
+/// Fulfillment order actions, checked against `supported_actions` (or the status, for `close` and
+/// `reschedule`) before sending.
+///
+/// Methods are suffixed with `fulfillment_order` because `OrderApi`, which is implemented for the
+/// same `Client` and exported from the same module, already has `get`, `cancel` and `close`.
+pub trait FulfillmentOrderApi {
+  fn get_fulfillment_order(&self, id: i64) -> ShopifyResult<FulfillmentOrder>;
+
+  fn hold_fulfillment_order(
+    &self,
+    fulfillment_order: &FulfillmentOrder,
+    hold: &FulfillmentHold,
+  ) -> ShopifyResult<FulfillmentOrder>;
+
+  fn release_fulfillment_order_hold(
+    &self,
+    fulfillment_order: &FulfillmentOrder,
+  ) -> ShopifyResult<FulfillmentOrder>;
+
+  fn cancel_fulfillment_order(
+    &self,
+    fulfillment_order: &FulfillmentOrder,
+  ) -> ShopifyResult<CancelFulfillmentOrderResponse>;
+
+  /// Marks an in-progress fulfillment order as incomplete.
+  fn close_fulfillment_order(
+    &self,
+    fulfillment_order: &FulfillmentOrder,
+    message: Option<&str>,
+  ) -> ShopifyResult<FulfillmentOrder>;
+
+  /// Marks a scheduled fulfillment order as ready for fulfillment.
+  fn open_fulfillment_order(
+    &self,
+    fulfillment_order: &FulfillmentOrder,
+  ) -> ShopifyResult<FulfillmentOrder>;
+
+  /// Updates the fulfill-at time of a scheduled fulfillment order.
+  fn reschedule_fulfillment_order(
+    &self,
+    fulfillment_order: &FulfillmentOrder,
+    new_fulfill_at: DateTime<Utc>,
+  ) -> ShopifyResult<FulfillmentOrder>;
+
+  fn set_fulfillment_orders_deadline(
+    &self,
+    fulfillment_order_ids: &[i64],
+    fulfillment_deadline: DateTime<Utc>,
+  ) -> ShopifyResult<()>;
+}
+
+impl FulfillmentOrderApi for Client {
+  fn get_fulfillment_order(&self, id: i64) -> ShopifyResult<FulfillmentOrder> {
+    shopify_wrap! {
+      pub struct Res {
+        fulfillment_order: FulfillmentOrder,
+      }
+    }
+    let path = format!("/admin/api/2023-07/fulfillment_orders/{}.json", id);
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn hold_fulfillment_order(
+    &self,
+    fulfillment_order: &FulfillmentOrder,
+    hold: &FulfillmentHold,
+  ) -> ShopifyResult<FulfillmentOrder> {
+    shopify_wrap! {
+      pub struct Res {
+        fulfillment_order: FulfillmentOrder,
+      }
+    }
+    fulfillment_order.ensure_supports(FulfillmentOrderAction::Hold)?;
+    let path = format!(
+      "/admin/api/2023-07/fulfillment_orders/{}/hold.json",
+      fulfillment_order.id
+    );
+    let res: Res = self.request(Method::POST, &path, move |b| {
+      b.json(&json!({ "fulfillment_hold": hold }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn release_fulfillment_order_hold(
+    &self,
+    fulfillment_order: &FulfillmentOrder,
+  ) -> ShopifyResult<FulfillmentOrder> {
+    shopify_wrap! {
+      pub struct Res {
+        fulfillment_order: FulfillmentOrder,
+      }
+    }
+    fulfillment_order.ensure_supports(FulfillmentOrderAction::ReleaseHold)?;
+    let path = format!(
+      "/admin/api/2023-07/fulfillment_orders/{}/release_hold.json",
+      fulfillment_order.id
+    );
+    let res: Res = self.request(Method::POST, &path, |b| b.json(&json!({})))?;
+    Ok(res.into_inner())
+  }
+
+  fn cancel_fulfillment_order(
+    &self,
+    fulfillment_order: &FulfillmentOrder,
+  ) -> ShopifyResult<CancelFulfillmentOrderResponse> {
+    fulfillment_order.ensure_supports(FulfillmentOrderAction::CancelFulfillmentOrder)?;
+    let path = format!(
+      "/admin/api/2023-07/fulfillment_orders/{}/cancel.json",
+      fulfillment_order.id
+    );
+    self.request(Method::POST, &path, |b| b.json(&json!({})))
+  }
+
+  fn close_fulfillment_order(
+    &self,
+    fulfillment_order: &FulfillmentOrder,
+    message: Option<&str>,
+  ) -> ShopifyResult<FulfillmentOrder> {
+    shopify_wrap! {
+      pub struct Res {
+        fulfillment_order: FulfillmentOrder,
+      }
+    }
+    fulfillment_order.ensure_status(FulfillmentOrderStatus::InProgress, "close")?;
+    let path = format!(
+      "/admin/api/2023-07/fulfillment_orders/{}/close.json",
+      fulfillment_order.id
+    );
+    let res: Res = self.request(Method::POST, &path, move |b| {
+      b.json(&json!({ "fulfillment_order": { "message": message } }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn open_fulfillment_order(
+    &self,
+    fulfillment_order: &FulfillmentOrder,
+  ) -> ShopifyResult<FulfillmentOrder> {
+    shopify_wrap! {
+      pub struct Res {
+        fulfillment_order: FulfillmentOrder,
+      }
+    }
+    fulfillment_order.ensure_supports(FulfillmentOrderAction::MarkAsOpen)?;
+    let path = format!(
+      "/admin/api/2023-07/fulfillment_orders/{}/open.json",
+      fulfillment_order.id
+    );
+    let res: Res = self.request(Method::POST, &path, |b| b.json(&json!({})))?;
+    Ok(res.into_inner())
+  }
+
+  fn reschedule_fulfillment_order(
+    &self,
+    fulfillment_order: &FulfillmentOrder,
+    new_fulfill_at: DateTime<Utc>,
+  ) -> ShopifyResult<FulfillmentOrder> {
+    shopify_wrap! {
+      pub struct Res {
+        fulfillment_order: FulfillmentOrder,
+      }
+    }
+    fulfillment_order.ensure_status(FulfillmentOrderStatus::Scheduled, "reschedule")?;
+    let path = format!(
+      "/admin/api/2023-07/fulfillment_orders/{}/reschedule.json",
+      fulfillment_order.id
+    );
+    let res: Res = self.request(Method::POST, &path, move |b| {
+      b.json(&json!({ "fulfillment_order": { "new_fulfill_at": new_fulfill_at } }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn set_fulfillment_orders_deadline(
+    &self,
+    fulfillment_order_ids: &[i64],
+    fulfillment_deadline: DateTime<Utc>,
+  ) -> ShopifyResult<()> {
+    use serde_json::Value;
+    self.request::<Value, _>(
+      Method::POST,
+      "/admin/api/2023-07/fulfillment_orders/set_fulfillment_orders_deadline.json",
+      move |b| {
+        b.json(&json!({
+          "fulfillment_order_ids": fulfillment_order_ids,
+          "fulfillment_deadline": fulfillment_deadline,
+        }))
+      },
+    )?;
+    Ok(())
+  }
+}
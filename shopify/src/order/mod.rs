@@ -8,6 +8,16 @@ mod fulfillment_order;
 pub use self::fulfillment_order::*;
 mod fulfillment;
 pub use self::fulfillment::*;
+mod fulfillment_event;
+pub use self::fulfillment_event::*;
+mod fulfill;
+pub use self::fulfill::{FulfillItem, FulfillItemRef, FulfillRequest};
+mod new_order;
+pub use self::new_order::*;
+mod refund;
+pub use self::refund::*;
+mod transaction;
+pub use self::transaction::*;
 
 request_query! {
   pub struct GetOrderListParams {
@@ -28,11 +38,25 @@ request_query! {
   }
 }
 
+request_query! {
+  pub struct GetFulfillmentListParams {
+    pub limit: Option<i64>,
+    pub since_id: Option<i64>,
+    pub created_at_min: Option<DateTime<Utc>>,
+    pub created_at_max: Option<DateTime<Utc>>,
+    pub updated_at_min: Option<DateTime<Utc>>,
+    pub updated_at_max: Option<DateTime<Utc>>,
+    pub fields: Option<Vec<String>>,
+  }
+}
+
 pub trait OrderApi {
   fn get_list(&self, params: &GetOrderListParams) -> ShopifyResult<Vec<Order>>;
 
   fn get(&self, id: i64) -> ShopifyResult<Order>;
 
+  fn create(&self, order: &NewOrder) -> ShopifyResult<Order>;
+
   fn get_risks(&self, order_id: i64) -> ShopifyResult<Vec<OrderRisk>>;
 
   fn get_fulfillment_orders(&self, order_id: i64) -> ShopifyResult<Vec<FulfillmentOrder>>;
@@ -43,11 +67,36 @@ pub trait OrderApi {
     move_fulfillment_order: &MoveFulfillmentOrderRequest,
   ) -> ShopifyResult<MoveFulfillmentOrderResponse>;
 
+  fn get_fulfillments(
+    &self,
+    order_id: i64,
+    params: &GetFulfillmentListParams,
+  ) -> ShopifyResult<Vec<Fulfillment>>;
+
+  fn get_fulfillment_order_fulfillments(
+    &self,
+    fulfillment_order_id: i64,
+  ) -> ShopifyResult<Vec<Fulfillment>>;
+
+  fn get_fulfillment(&self, order_id: i64, fulfillment_id: i64) -> ShopifyResult<Fulfillment>;
+
   fn create_fulfillment(
     &self,
     fulfillment: &CreateFulfillmentRequest,
   ) -> ShopifyResult<Fulfillment>;
 
+  fn cancel_fulfillment(&self, fulfillment_id: i64) -> ShopifyResult<Fulfillment>;
+
+  /// Fulfills line items (or SKUs) of an order from the given locations.
+  ///
+  /// Handles finding the fulfillment orders holding the items, splitting quantities across
+  /// them and moving them to the requested locations. Returns one fulfillment per location.
+  ///
+  /// If a step fails after something was changed on Shopify, the error is
+  /// [`ShopifyError::PartialFulfillment`] with the moved fulfillment orders and the created
+  /// fulfillments.
+  fn fulfill(&self, order: &Order, request: &FulfillRequest) -> ShopifyResult<Vec<Fulfillment>>;
+
   fn update_fulfillment_tracking(
     &self,
     fulfillment_id: i64,
@@ -56,6 +105,14 @@ pub trait OrderApi {
   ) -> ShopifyResult<Fulfillment>;
 
   fn update_order(&self, order: &Order, update_params: OrderUpdateParams) -> ShopifyResult<Order>;
+
+  fn cancel(&self, id: i64, params: &CancelOrderParams) -> ShopifyResult<CancelOrderResponse>;
+
+  fn close(&self, id: i64) -> ShopifyResult<Order>;
+
+  fn reopen(&self, id: i64) -> ShopifyResult<Order>;
+
+  fn delete(&self, id: i64) -> ShopifyResult<()>;
 }
 
 impl OrderApi for Client {
@@ -90,6 +147,19 @@ impl OrderApi for Client {
     Ok(res.into_inner())
   }
 
+  fn create(&self, order: &NewOrder) -> ShopifyResult<Order> {
+    shopify_wrap! {
+      pub struct Res {
+        order: Order,
+      }
+    }
+
+    let res: Res = self.request(Method::POST, "/admin/api/2023-07/orders.json", move |b| {
+      b.json(&json!({ "order": order }))
+    })?;
+    Ok(res.into_inner())
+  }
+
   fn get_risks(&self, order_id: i64) -> ShopifyResult<Vec<OrderRisk>> {
     shopify_wrap! {
       pub struct Res {
@@ -132,6 +202,66 @@ impl OrderApi for Client {
     Ok(res)
   }
 
+  fn get_fulfillments(
+    &self,
+    order_id: i64,
+    params: &GetFulfillmentListParams,
+  ) -> ShopifyResult<Vec<Fulfillment>> {
+    shopify_wrap! {
+      pub struct Res {
+        fulfillments: Vec<Fulfillment>,
+      }
+    }
+    let path = format!("/admin/api/2023-07/orders/{}/fulfillments.json", order_id);
+    let res: Res = self.request_with_params(Method::GET, &path, params, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn get_fulfillment_order_fulfillments(
+    &self,
+    fulfillment_order_id: i64,
+  ) -> ShopifyResult<Vec<Fulfillment>> {
+    shopify_wrap! {
+      pub struct Res {
+        fulfillments: Vec<Fulfillment>,
+      }
+    }
+    let path = format!(
+      "/admin/api/2023-07/fulfillment_orders/{}/fulfillments.json",
+      fulfillment_order_id
+    );
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn get_fulfillment(&self, order_id: i64, fulfillment_id: i64) -> ShopifyResult<Fulfillment> {
+    shopify_wrap! {
+      pub struct Res {
+        fulfillment: Fulfillment,
+      }
+    }
+    let path = format!(
+      "/admin/api/2023-07/orders/{}/fulfillments/{}.json",
+      order_id, fulfillment_id
+    );
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn cancel_fulfillment(&self, fulfillment_id: i64) -> ShopifyResult<Fulfillment> {
+    shopify_wrap! {
+      pub struct Res {
+        fulfillment: Fulfillment,
+      }
+    }
+    let path = format!(
+      "/admin/api/2023-07/fulfillments/{}/cancel.json",
+      fulfillment_id
+    );
+    let res: Res = self.request(Method::POST, &path, |b| b.json(&json!({})))?;
+    Ok(res.into_inner())
+  }
+
   fn create_fulfillment(
     &self,
     fulfillment: &CreateFulfillmentRequest,
@@ -153,6 +283,10 @@ impl OrderApi for Client {
     Ok(res.into_inner())
   }
 
+  fn fulfill(&self, order: &Order, request: &FulfillRequest) -> ShopifyResult<Vec<Fulfillment>> {
+    fulfill::fulfill(self, order, request)
+  }
+
   fn update_fulfillment_tracking(
     &self,
     fulfillment_id: i64,
@@ -185,8 +319,7 @@ impl OrderApi for Client {
   fn update_order(&self, order: &Order, update_params: OrderUpdateParams) -> ShopifyResult<Order> {
     let order_update_request_params = OrderUpdateRequestParams {
       order_id: order.id,
-      buyer_accepts_marketing: update_params
-        .buyer_accepts_marketing,
+      buyer_accepts_marketing: update_params.buyer_accepts_marketing,
       email: update_params.email,
       phone: update_params.phone,
       note: update_params.note,
@@ -211,6 +344,47 @@ impl OrderApi for Client {
     )?;
     Ok(res.into_inner())
   }
+
+  fn cancel(&self, id: i64, params: &CancelOrderParams) -> ShopifyResult<CancelOrderResponse> {
+    self.request(
+      Method::POST,
+      &format!("/admin/api/2023-07/orders/{}/cancel.json", id),
+      move |b| b.json(params),
+    )
+  }
+
+  fn close(&self, id: i64) -> ShopifyResult<Order> {
+    shopify_wrap! {
+      pub struct Res {
+        order: Order,
+      }
+    }
+    let res: Res = self.request(
+      Method::POST,
+      &format!("/admin/api/2023-07/orders/{}/close.json", id),
+      |b| b.json(&json!({})),
+    )?;
+    Ok(res.into_inner())
+  }
+
+  fn reopen(&self, id: i64) -> ShopifyResult<Order> {
+    shopify_wrap! {
+      pub struct Res {
+        order: Order,
+      }
+    }
+    let res: Res = self.request(
+      Method::POST,
+      &format!("/admin/api/2023-07/orders/{}/open.json", id),
+      |b| b.json(&json!({})),
+    )?;
+    Ok(res.into_inner())
+  }
+
+  fn delete(&self, id: i64) -> ShopifyResult<()> {
+    let path = format!("/admin/api/2023-07/orders/{}.json", id);
+    self.request_no_content(Method::DELETE, &path, std::convert::identity)
+  }
 }
 
 #[cfg(test)]
@@ -306,6 +480,534 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_cancel_order() {
+    let params = CancelOrderParams {
+      reason: Some(CancelReason::Inventory),
+      restock: Some(true),
+      ..Default::default()
+    };
+    assert_eq!(
+      serde_json::to_value(&params).unwrap(),
+      serde_json::json!({ "reason": "inventory", "restock": true })
+    );
+
+    let res: CancelOrderResponse = serde_json::from_str(
+      r#"{ "notice": "Order cancellation was scheduled", "job": { "id": 1, "done": false } }"#,
+    )
+    .unwrap();
+    match res {
+      CancelOrderResponse::Scheduled { job: Some(job), .. } => {
+        assert_eq!((job.id, job.done), (1, false))
+      }
+      other => panic!("unexpected response: {:?}", other),
+    }
+
+    let err = serde_json::from_str::<CancelOrderResponse>(r#"{ "order": { "id": 1 } }"#)
+      .unwrap_err()
+      .to_string();
+    assert!(err.contains("missing field"), "{}", err);
+  }
+
+  #[test]
+  fn test_new_order() {
+    let order = NewOrder::new()
+      .line_item(NewLineItem::variant(447654529, 2).price("9.99"))
+      .line_item(NewLineItem::custom("Gift wrapping", "5.00", 1))
+      .customer(NewOrderCustomer {
+        id: Some(207119551),
+        ..Default::default()
+      })
+      .financial_status(FinancialStatus::Paid)
+      .inventory_behaviour(InventoryBehaviour::DecrementObeyingPolicy)
+      .send_receipt(false)
+      .tag("amazon")
+      .tag("imported")
+      .note_attribute("marketplace_order_id", "111-222");
+    assert_eq!(
+      serde_json::to_value(&order).unwrap(),
+      serde_json::json!({
+        "line_items": [
+          { "variant_id": 447654529, "price": "9.99", "quantity": 2 },
+          { "title": "Gift wrapping", "price": "5.00", "quantity": 1 }
+        ],
+        "customer": { "id": 207119551 },
+        "financial_status": "paid",
+        "inventory_behaviour": "decrement_obeying_policy",
+        "send_receipt": false,
+        "note_attributes": [{ "name": "marketplace_order_id", "value": "111-222" }],
+        "tags": "amazon, imported"
+      })
+    );
+  }
+
+  #[test]
+  fn test_new_refund_from_calculated() {
+    let calculated: CalculatedRefund = serde_json::from_str(
+      r##"
+    {
+      "shipping": {
+        "amount": "5.00",
+        "tax": "0.00",
+        "maximum_refundable": "5.00"
+      },
+      "duties": [],
+      "refund_line_items": [
+        {
+          "quantity": 1,
+          "line_item_id": 518995019,
+          "location_id": 487838322,
+          "restock_type": "return",
+          "price": "199.00",
+          "subtotal": "195.67",
+          "total_tax": "3.98",
+          "discounted_price": "199.00",
+          "discounted_total_price": "199.00",
+          "total_cart_discount_amount": "3.33"
+        }
+      ],
+      "transactions": [
+        {
+          "order_id": 450789469,
+          "kind": "suggested_refund",
+          "gateway": "bogus",
+          "parent_id": 801038806,
+          "amount": "41.94",
+          "currency": "USD",
+          "maximum_refundable": "41.94"
+        }
+      ],
+      "currency": "USD"
+    }
+    "##,
+    )
+    .unwrap();
+
+    let refund = NewRefund {
+      notify: Some(true),
+      ..NewRefund::from_calculated(&calculated)
+    };
+    assert_eq!(
+      serde_json::to_value(&refund).unwrap(),
+      serde_json::json!({
+        "currency": "USD",
+        "notify": true,
+        "shipping": { "amount": "5.00" },
+        "refund_line_items": [
+          {
+            "line_item_id": 518995019,
+            "quantity": 1,
+            "restock_type": "return",
+            "location_id": 487838322
+          }
+        ],
+        "transactions": [
+          { "parent_id": 801038806, "amount": "41.94", "kind": "refund", "gateway": "bogus" }
+        ]
+      })
+    );
+  }
+
+  #[test]
+  fn test_deserialize_refund() {
+    let refund: Refund = serde_json::from_str(
+      r##"
+    {
+      "id": 929361464,
+      "order_id": 450789469,
+      "created_at": "2023-07-11T18:38:14-04:00",
+      "note": "wrong size",
+      "user_id": null,
+      "processed_at": "2023-07-11T18:38:14-04:00",
+      "restock": false,
+      "duties": [],
+      "total_duties_set": null,
+      "admin_graphql_api_id": "gid://shopify/Refund/929361464",
+      "refund_line_items": [
+        {
+          "id": 1058498309,
+          "quantity": 1,
+          "line_item_id": 518995019,
+          "location_id": 487838322,
+          "restock_type": "return",
+          "subtotal": 195.66,
+          "total_tax": 3.98,
+          "subtotal_set": {
+            "shop_money": { "amount": "195.66", "currency_code": "USD" },
+            "presentment_money": { "amount": "195.66", "currency_code": "USD" }
+          }
+        }
+      ],
+      "transactions": [
+        {
+          "id": 1068278480,
+          "order_id": 450789469,
+          "kind": "refund",
+          "gateway": "bogus",
+          "status": "success",
+          "amount": "41.94",
+          "parent_id": 801038806
+        }
+      ],
+      "order_adjustments": [
+        {
+          "id": 1030976844,
+          "order_id": 450789469,
+          "refund_id": 929361464,
+          "amount": "-5.00",
+          "tax_amount": "0.00",
+          "kind": "shipping_refund",
+          "reason": "Shipping refund"
+        }
+      ]
+    }
+    "##,
+    )
+    .unwrap();
+    assert_eq!(
+      refund.refund_line_items[0].restock_type,
+      Some(RestockType::Return)
+    );
+    assert_eq!(
+      refund.order_adjustments[0].kind,
+      OrderAdjustmentKind::ShippingRefund
+    );
+    assert_eq!(refund.transactions[0].kind, TransactionKind::Refund);
+  }
+
+  #[test]
+  fn test_deserialize_refund_with_unknown_values() {
+    let refund: Refund = serde_json::from_str(
+      r##"
+    {
+      "id": 929361465,
+      "order_id": 450789469,
+      "refund_line_items": [
+        {
+          "id": 1058498310,
+          "quantity": 1,
+          "line_item_id": 518995019,
+          "restock_type": "exchange",
+          "line_item": { "id": 518995019, "title": "IPod Nano - 8gb" }
+        }
+      ],
+      "transactions": [
+        {
+          "id": 1068278481,
+          "kind": "change",
+          "status": "queued",
+          "amount": "1.00"
+        }
+      ],
+      "order_adjustments": [
+        {
+          "id": 1030976845,
+          "amount": "-1.00",
+          "kind": "store_credit"
+        }
+      ]
+    }
+    "##,
+    )
+    .unwrap();
+    assert_eq!(
+      refund.refund_line_items[0].restock_type,
+      Some(RestockType::Other)
+    );
+    assert!(refund.refund_line_items[0].line_item.is_none());
+    assert_eq!(refund.transactions[0].kind, TransactionKind::Change);
+    assert_eq!(
+      refund.transactions[0].status,
+      Some(TransactionStatus::Other)
+    );
+    assert_eq!(refund.order_adjustments[0].kind, OrderAdjustmentKind::Other);
+  }
+
+  #[test]
+  fn test_deserialize_transaction() {
+    let transaction: Transaction = serde_json::from_str(
+      r##"
+    {
+      "id": 389404469,
+      "order_id": 450789469,
+      "kind": "authorization",
+      "gateway": "bogus",
+      "status": "success",
+      "message": null,
+      "created_at": "2005-08-01T11:57:11-04:00",
+      "test": false,
+      "authorization": "authorization-key",
+      "location_id": null,
+      "user_id": null,
+      "parent_id": null,
+      "processed_at": "2005-08-01T11:57:11-04:00",
+      "device_id": null,
+      "error_code": null,
+      "source_name": "web",
+      "payment_details": {
+        "credit_card_bin": null,
+        "credit_card_company": "Visa",
+        "credit_card_number": "•••• •••• •••• 4242"
+      },
+      "receipt": { "testcase": true, "authorization": "123456" },
+      "amount": "598.94",
+      "currency": "USD",
+      "payment_id": "#1001.1",
+      "total_unsettled_set": {
+        "presentment_money": { "amount": "598.94", "currency": "USD" },
+        "shop_money": { "amount": "598.94", "currency": "USD" }
+      },
+      "manual_payment_gateway": false,
+      "admin_graphql_api_id": "gid://shopify/OrderTransaction/389404469"
+    }
+    "##,
+    )
+    .unwrap();
+    assert_eq!(transaction.kind, TransactionKind::Authorization);
+    assert_eq!(transaction.status, Some(TransactionStatus::Success));
+
+    assert_eq!(
+      serde_json::to_value(NewTransaction::capture(transaction.id, None)).unwrap(),
+      serde_json::json!({ "kind": "capture", "parent_id": 389404469 })
+    );
+  }
+
+  #[test]
+  fn test_financial_status_from_transactions() {
+    fn transaction(kind: &str, status: &str, amount: &str) -> Transaction {
+      serde_json::from_value(serde_json::json!({
+        "id": 1,
+        "kind": kind,
+        "status": status,
+        "amount": amount,
+      }))
+      .unwrap()
+    }
+
+    let status = |transactions: Vec<Transaction>| {
+      FinancialStatus::from_transactions("10.00", &transactions).unwrap()
+    };
+    assert_eq!(status(vec![]), FinancialStatus::Pending);
+    assert_eq!(
+      status(vec![transaction("authorization", "success", "10.00")]),
+      FinancialStatus::Authorized
+    );
+    assert_eq!(
+      status(vec![
+        transaction("authorization", "success", "10.00"),
+        transaction("void", "success", "0.00"),
+      ]),
+      FinancialStatus::Voided
+    );
+    assert_eq!(
+      status(vec![
+        transaction("authorization", "success", "10.00"),
+        transaction("capture", "success", "4.5"),
+        transaction("capture", "failure", "5.50"),
+      ]),
+      FinancialStatus::PartiallyPaid
+    );
+    assert_eq!(
+      status(vec![transaction("sale", "success", "10.00")]),
+      FinancialStatus::Paid
+    );
+    assert_eq!(
+      status(vec![
+        transaction("sale", "success", "10.00"),
+        transaction("refund", "success", "0.01"),
+      ]),
+      FinancialStatus::PartiallyRefunded
+    );
+    assert_eq!(
+      status(vec![
+        transaction("sale", "success", "10.00"),
+        transaction("refund", "success", "10"),
+      ]),
+      FinancialStatus::Refunded
+    );
+    assert_eq!(
+      FinancialStatus::from_transactions("10.00", &[transaction("sale", "success", "1e3")]),
+      None
+    );
+    for amount in ["--5", "-", ".5", "9223372036854775807"] {
+      assert_eq!(
+        FinancialStatus::from_transactions("10.00", &[transaction("sale", "success", amount)]),
+        None,
+        "{}",
+        amount
+      );
+    }
+    assert_eq!(
+      FinancialStatus::from_transactions(
+        "10.00",
+        &[
+          transaction("sale", "success", "9223372036854775.000"),
+          transaction("sale", "success", "9223372036854775.000"),
+        ]
+      ),
+      None
+    );
+  }
+
+  #[test]
+  fn test_deserialize_discount_code() {
+    let code: DiscountCode = serde_json::from_value(json!({
+      "amount": "0.00",
+      "code": "BOGO",
+      "type": "buy_x_get_y"
+    }))
+    .unwrap();
+    assert_eq!(code.type_, DiscountCodeType::Other);
+  }
+
+  #[test]
+  fn test_fulfillment_order_supported_actions() {
+    let fulfillment_order: FulfillmentOrder = serde_json::from_str(
+      r##"
+    {
+      "id": 1046000778,
+      "shop_id": 548380009,
+      "order_id": 450789469,
+      "assigned_location_id": 24826418,
+      "request_status": "unsubmitted",
+      "status": "open",
+      "supported_actions": ["create_fulfillment", "move", "hold"],
+      "line_items": [],
+      "fulfill_at": null,
+      "fulfillment_holds": []
+    }
+    "##,
+    )
+    .unwrap();
+    assert!(fulfillment_order.supports(FulfillmentOrderAction::Hold));
+    assert!(!fulfillment_order.supports(FulfillmentOrderAction::ReleaseHold));
+
+    let client = Client::new("https://example.myshopify.com", "key", "password").unwrap();
+    let err = client
+      .release_fulfillment_order_hold(&fulfillment_order)
+      .unwrap_err();
+    assert!(matches!(
+      err,
+      ShopifyError::UnsupportedFulfillmentOrderAction {
+        id: 1046000778,
+        action: "release_hold",
+      }
+    ));
+
+    let err = client
+      .close_fulfillment_order(&fulfillment_order, None)
+      .unwrap_err();
+    assert!(matches!(
+      err,
+      ShopifyError::UnsupportedFulfillmentOrderAction {
+        action: "close",
+        ..
+      }
+    ));
+    let err = client
+      .reschedule_fulfillment_order(&fulfillment_order, Utc::now())
+      .unwrap_err();
+    assert!(matches!(
+      err,
+      ShopifyError::UnsupportedFulfillmentOrderAction {
+        action: "reschedule",
+        ..
+      }
+    ));
+  }
+
+  #[test]
+  fn test_fulfillment_event() {
+    let event: FulfillmentEvent = serde_json::from_str(
+      r##"
+    {
+      "id": 944956391,
+      "fulfillment_id": 255858046,
+      "status": "in_transit",
+      "message": null,
+      "happened_at": "2023-07-11T18:38:44-04:00",
+      "city": null,
+      "province": null,
+      "country": null,
+      "zip": null,
+      "address1": null,
+      "latitude": null,
+      "longitude": null,
+      "shop_id": 548380009,
+      "created_at": "2023-07-11T18:38:44-04:00",
+      "updated_at": "2023-07-11T18:38:44-04:00",
+      "estimated_delivery_at": null,
+      "order_id": 450789469,
+      "admin_graphql_api_id": "gid://shopify/FulfillmentEvent/944956391"
+    }
+    "##,
+    )
+    .unwrap();
+    assert_eq!(event.status, FulfillmentEventStatus::InTransit);
+    for (status, expected) in [
+      ("label_purchased", FulfillmentEventStatus::LabelPurchased),
+      ("carrier_picked_up", FulfillmentEventStatus::CarrierPickedUp),
+      ("picked_up", FulfillmentEventStatus::PickedUp),
+      ("returned_to_sender", FulfillmentEventStatus::Other),
+    ] {
+      assert_eq!(
+        serde_json::from_value::<FulfillmentEventStatus>(serde_json::json!(status)).unwrap(),
+        expected
+      );
+    }
+
+    let new_event = NewFulfillmentEvent {
+      city: Some("Toronto".to_owned()),
+      ..NewFulfillmentEvent::new(FulfillmentEventStatus::OutForDelivery)
+    };
+    assert_eq!(
+      serde_json::to_value(&new_event).unwrap(),
+      serde_json::json!({ "status": "out_for_delivery", "city": "Toronto" })
+    );
+  }
+
+  #[test]
+  fn test_tracking_info_new() {
+    let tracking_info = TrackingInfo::new("ups", "1Z001985YW99744790").unwrap();
+    assert_eq!(tracking_info.company, "UPS");
+    assert!(tracking_info.url.is_some());
+
+    assert!(matches!(
+      TrackingInfo::new("ups", "12345"),
+      Err(ShopifyError::InvalidTrackingNumber { .. })
+    ));
+
+    let tracking_info = TrackingInfo::new("Acme Freight", "A-1")
+      .unwrap()
+      .url_template("https://track.example.com/{number}");
+    assert_eq!(tracking_info.company, "Acme Freight");
+    assert_eq!(
+      tracking_info.url.as_deref(),
+      Some("https://track.example.com/A1")
+    );
+  }
+
+  #[test]
+  fn test_tracking_info_multiple_numbers() {
+    let tracking_info = TrackingInfo {
+      company: "UPS".to_owned(),
+      number: "1Z001985YW99744790".to_owned(),
+      numbers: vec![
+        "1Z001985YW99744790".to_owned(),
+        "1Z001985YW99744791".to_owned(),
+      ],
+      ..Default::default()
+    };
+    assert_eq!(
+      serde_json::to_value(&tracking_info).unwrap(),
+      serde_json::json!({
+        "company": "UPS",
+        "number": "1Z001985YW99744790",
+        "numbers": ["1Z001985YW99744790", "1Z001985YW99744791"]
+      })
+    );
+  }
+
   #[test]
   #[ignore]
   /// Add a `test_tag` tag to an order for which the ID is passed through the `ORDER_ID` env var.
@@ -0,0 +1,331 @@
+use crate::order::{
+  Address, FinancialStatus, Property, TaxLines, TransactionKind, TransactionStatus,
+};
+use crate::types::{DateTime, Utc};
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum InventoryBehaviour {
+  /// Do not claim inventory.
+  Bypass,
+  /// Ignore the product's inventory policy and claim inventory.
+  DecrementIgnoringPolicy,
+  /// Follow the product's inventory policy and claim inventory, if possible.
+  DecrementObeyingPolicy,
+}
+
+/// A line item of a [`NewOrder`], either referencing a product variant or a custom item.
+#[derive(Debug, Serialize)]
+pub struct NewLineItem {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub variant_id: Option<i64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub price: Option<String>,
+  pub quantity: i64,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sku: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub grams: Option<i64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub requires_shipping: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub taxable: Option<bool>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub tax_lines: Vec<TaxLines>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub properties: Vec<Property>,
+}
+
+impl NewLineItem {
+  /// A line item for an existing product variant. Title and price default to the variant's.
+  pub fn variant(variant_id: i64, quantity: i64) -> Self {
+    NewLineItem {
+      variant_id: Some(variant_id),
+      title: None,
+      price: None,
+      quantity,
+      sku: None,
+      grams: None,
+      requires_shipping: None,
+      taxable: None,
+      tax_lines: vec![],
+      properties: vec![],
+    }
+  }
+
+  /// A custom line item that is not associated with a product.
+  pub fn custom(title: &str, price: &str, quantity: i64) -> Self {
+    NewLineItem {
+      variant_id: None,
+      title: Some(title.to_owned()),
+      price: Some(price.to_owned()),
+      ..Self::variant(0, quantity)
+    }
+  }
+
+  pub fn price(self, price: &str) -> Self {
+    Self {
+      price: Some(price.to_owned()),
+      ..self
+    }
+  }
+
+  pub fn sku(self, sku: &str) -> Self {
+    Self {
+      sku: Some(sku.to_owned()),
+      ..self
+    }
+  }
+
+  pub fn tax_line(mut self, tax_line: TaxLines) -> Self {
+    self.tax_lines.push(tax_line);
+    self
+  }
+
+  pub fn property(mut self, name: &str, value: &str) -> Self {
+    self.properties.push(Property {
+      name: name.to_owned(),
+      value: Some(value.into()),
+    });
+    self
+  }
+}
+
+/// The customer of a [`NewOrder`]. Either an existing customer `id`, or the details of a customer
+/// that Shopify will look up by email or create.
+#[derive(Debug, Serialize, Default)]
+pub struct NewOrderCustomer {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub id: Option<i64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub email: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub first_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub last_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub phone: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NewShippingLine {
+  pub title: String,
+  pub price: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub code: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub source: Option<String>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub tax_lines: Vec<TaxLines>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NewOrderTransaction {
+  pub kind: TransactionKind,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub status: Option<TransactionStatus>,
+  pub amount: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub gateway: Option<String>,
+}
+
+/// An order to create with [`OrderApi::create`](crate::order::OrderApi::create).
+///
+/// ```
+/// use shopify::order::{FinancialStatus, NewLineItem, NewOrder};
+///
+/// let order = NewOrder::new()
+///   .line_item(NewLineItem::variant(447654529, 1))
+///   .line_item(NewLineItem::custom("Gift wrapping", "5.00", 1))
+///   .email("jane@example.com")
+///   .financial_status(FinancialStatus::Paid)
+///   .tag("marketplace");
+/// ```
+#[derive(Debug, Serialize, Default)]
+pub struct NewOrder {
+  pub line_items: Vec<NewLineItem>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub email: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub phone: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub customer: Option<NewOrderCustomer>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub billing_address: Option<Address>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub shipping_address: Option<Address>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub shipping_lines: Vec<NewShippingLine>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub tax_lines: Vec<TaxLines>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub taxes_included: Option<bool>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub transactions: Vec<NewOrderTransaction>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub financial_status: Option<FinancialStatus>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub inventory_behaviour: Option<InventoryBehaviour>,
+  /// Whether to send an order confirmation to the customer.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub send_receipt: Option<bool>,
+  /// Whether to send a shipping confirmation to the customer.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub send_fulfillment_receipt: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub currency: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub note: Option<String>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub note_attributes: Vec<Property>,
+  /// Comma-separated tags.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tags: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub source_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub source_identifier: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub processed_at: Option<DateTime<Utc>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub test: Option<bool>,
+}
+
+impl NewOrder {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn line_item(mut self, line_item: NewLineItem) -> Self {
+    self.line_items.push(line_item);
+    self
+  }
+
+  pub fn email(self, email: &str) -> Self {
+    Self {
+      email: Some(email.to_owned()),
+      ..self
+    }
+  }
+
+  pub fn phone(self, phone: &str) -> Self {
+    Self {
+      phone: Some(phone.to_owned()),
+      ..self
+    }
+  }
+
+  pub fn customer(self, customer: NewOrderCustomer) -> Self {
+    Self {
+      customer: Some(customer),
+      ..self
+    }
+  }
+
+  pub fn billing_address(self, address: Address) -> Self {
+    Self {
+      billing_address: Some(address),
+      ..self
+    }
+  }
+
+  pub fn shipping_address(self, address: Address) -> Self {
+    Self {
+      shipping_address: Some(address),
+      ..self
+    }
+  }
+
+  pub fn shipping_line(mut self, shipping_line: NewShippingLine) -> Self {
+    self.shipping_lines.push(shipping_line);
+    self
+  }
+
+  pub fn tax_line(mut self, tax_line: TaxLines) -> Self {
+    self.tax_lines.push(tax_line);
+    self
+  }
+
+  pub fn transaction(mut self, transaction: NewOrderTransaction) -> Self {
+    self.transactions.push(transaction);
+    self
+  }
+
+  pub fn financial_status(self, financial_status: FinancialStatus) -> Self {
+    Self {
+      financial_status: Some(financial_status),
+      ..self
+    }
+  }
+
+  pub fn inventory_behaviour(self, inventory_behaviour: InventoryBehaviour) -> Self {
+    Self {
+      inventory_behaviour: Some(inventory_behaviour),
+      ..self
+    }
+  }
+
+  pub fn send_receipt(self, send_receipt: bool) -> Self {
+    Self {
+      send_receipt: Some(send_receipt),
+      ..self
+    }
+  }
+
+  pub fn send_fulfillment_receipt(self, send_fulfillment_receipt: bool) -> Self {
+    Self {
+      send_fulfillment_receipt: Some(send_fulfillment_receipt),
+      ..self
+    }
+  }
+
+  pub fn currency(self, currency: &str) -> Self {
+    Self {
+      currency: Some(currency.to_owned()),
+      ..self
+    }
+  }
+
+  pub fn note(self, note: &str) -> Self {
+    Self {
+      note: Some(note.to_owned()),
+      ..self
+    }
+  }
+
+  pub fn note_attribute(mut self, name: &str, value: &str) -> Self {
+    self.note_attributes.push(Property {
+      name: name.to_owned(),
+      value: Some(value.into()),
+    });
+    self
+  }
+
+  /// Appends a tag to the comma-separated `tags`.
+  pub fn tag(self, tag: &str) -> Self {
+    let tags = match self.tags {
+      Some(tags) if !tags.is_empty() => format!("{}, {}", tags, tag),
+      _ => tag.to_owned(),
+    };
+    Self {
+      tags: Some(tags),
+      ..self
+    }
+  }
+
+  pub fn source_name(self, source_name: &str) -> Self {
+    Self {
+      source_name: Some(source_name.to_owned()),
+      ..self
+    }
+  }
+
+  pub fn processed_at(self, processed_at: DateTime<Utc>) -> Self {
+    Self {
+      processed_at: Some(processed_at),
+      ..self
+    }
+  }
+}
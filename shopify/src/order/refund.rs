@@ -0,0 +1,276 @@
+use crate::client::{Client, Method};
+use crate::order::{LineItems, Set, Transaction};
+use crate::result::*;
+use crate::types::{DateTime, Utc, Value};
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RestockType {
+  /// Refunding these items won't affect inventory.
+  NoRestock,
+  /// The items have not yet been fulfilled. The canceled quantity will be added back to the available count.
+  Cancel,
+  /// The items were already delivered but will be returned to the merchant.
+  Return,
+  /// The deprecated `restock` property was used for this refund.
+  LegacyRestock,
+  #[serde(other)]
+  Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderAdjustmentKind {
+  ShippingRefund,
+  RefundDiscrepancy,
+  #[serde(other)]
+  Other,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Refund {
+  pub id: i64,
+  pub order_id: i64,
+  pub created_at: Option<DateTime<Utc>>,
+  pub processed_at: Option<DateTime<Utc>>,
+  pub note: Option<String>,
+  pub user_id: Option<i64>,
+  pub restock: Option<bool>,
+  #[serde(default)]
+  pub refund_line_items: Vec<RefundLineItem>,
+  #[serde(default)]
+  pub transactions: Vec<Transaction>,
+  #[serde(default)]
+  pub order_adjustments: Vec<OrderAdjustment>,
+  pub duties: Option<Vec<Value>>,
+  pub admin_graphql_api_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RefundLineItem {
+  pub id: Option<i64>,
+  pub line_item_id: i64,
+  pub quantity: i64,
+  pub location_id: Option<i64>,
+  pub restock_type: Option<RestockType>,
+  /// A string in calculated refunds, a number in created refunds.
+  pub subtotal: Option<Value>,
+  /// A string in calculated refunds, a number in created refunds.
+  pub total_tax: Option<Value>,
+  pub subtotal_set: Option<Set>,
+  pub total_tax_set: Option<Set>,
+  pub price: Option<String>,
+  pub discounted_price: Option<String>,
+  pub discounted_total_price: Option<String>,
+  pub total_cart_discount_amount: Option<String>,
+  /// `None` if the line item could not be decoded, the refund is still usable through `line_item_id`.
+  #[serde(default, deserialize_with = "deserialize_line_item")]
+  pub line_item: Option<LineItems>,
+}
+
+fn deserialize_line_item<'de, D>(d: D) -> Result<Option<LineItems>, D::Error>
+where
+  D: serde::Deserializer<'de>,
+{
+  use serde::Deserialize;
+  let value = Option::<Value>::deserialize(d)?;
+  Ok(value.and_then(|v| serde_json::from_value(v).ok()))
+}
+
+/// A refund transaction. Calculated refunds suggest transactions of kind `suggested_refund`,
+/// which have to be sent with kind `refund` when creating the refund.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RefundTransaction {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub parent_id: Option<i64>,
+  pub amount: String,
+  pub kind: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub gateway: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub maximum_refundable: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OrderAdjustment {
+  pub id: i64,
+  pub order_id: Option<i64>,
+  pub refund_id: Option<i64>,
+  pub amount: String,
+  pub tax_amount: Option<String>,
+  pub kind: OrderAdjustmentKind,
+  pub reason: Option<String>,
+  pub amount_set: Option<Set>,
+  pub tax_amount_set: Option<Set>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct RefundShipping {
+  /// Whether to refund all remaining shipping.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub full_refund: Option<bool>,
+  /// The amount of shipping to refund. Takes precedence over `full_refund`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub amount: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tax: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub maximum_refundable: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NewRefundLineItem {
+  pub line_item_id: i64,
+  pub quantity: i64,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub restock_type: Option<RestockType>,
+  /// The location where the items should be restocked. Required for `return` and `cancel`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub location_id: Option<i64>,
+}
+
+impl NewRefundLineItem {
+  /// Refunds the items without affecting inventory.
+  pub fn no_restock(line_item_id: i64, quantity: i64) -> Self {
+    NewRefundLineItem {
+      line_item_id,
+      quantity,
+      restock_type: Some(RestockType::NoRestock),
+      location_id: None,
+    }
+  }
+
+  /// Refunds the items and restocks them at `location_id`.
+  pub fn restock(
+    line_item_id: i64,
+    quantity: i64,
+    restock_type: RestockType,
+    location_id: i64,
+  ) -> Self {
+    NewRefundLineItem {
+      line_item_id,
+      quantity,
+      restock_type: Some(restock_type),
+      location_id: Some(location_id),
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct CalculateRefund {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub currency: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub shipping: Option<RefundShipping>,
+  pub refund_line_items: Vec<NewRefundLineItem>,
+}
+
+/// The result of [`RefundApi::calculate_refund`].
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CalculatedRefund {
+  pub currency: Option<String>,
+  pub shipping: Option<RefundShipping>,
+  pub refund_line_items: Vec<RefundLineItem>,
+  pub transactions: Vec<RefundTransaction>,
+  pub duties: Option<Vec<Value>>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct NewRefund {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub currency: Option<String>,
+  /// Whether to send a refund notification to the customer.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub notify: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub note: Option<String>,
+  /// An optional comment that explains a discrepancy between calculated and actual refund amounts.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub discrepancy_reason: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub shipping: Option<RefundShipping>,
+  pub refund_line_items: Vec<NewRefundLineItem>,
+  pub transactions: Vec<RefundTransaction>,
+}
+
+impl NewRefund {
+  /// Creates a refund that issues the transactions suggested by a calculated refund.
+  pub fn from_calculated(calculated: &CalculatedRefund) -> Self {
+    NewRefund {
+      currency: calculated.currency.clone(),
+      shipping: calculated.shipping.as_ref().map(|shipping| RefundShipping {
+        amount: shipping.amount.clone(),
+        ..Default::default()
+      }),
+      refund_line_items: calculated
+        .refund_line_items
+        .iter()
+        .map(|item| NewRefundLineItem {
+          line_item_id: item.line_item_id,
+          quantity: item.quantity,
+          restock_type: item.restock_type,
+          location_id: item.location_id,
+        })
+        .collect(),
+      transactions: calculated
+        .transactions
+        .iter()
+        .map(|t| RefundTransaction {
+          parent_id: t.parent_id,
+          amount: t.amount.clone(),
+          kind: "refund".to_owned(),
+          gateway: t.gateway.clone(),
+          maximum_refundable: None,
+        })
+        .collect(),
+      ..Default::default()
+    }
+  }
+}
+
+/// Methods are suffixed with `refund` because `OrderApi`, which is implemented for the same
+/// `Client` and exported from the same module, already has `create`.
+pub trait RefundApi {
+  fn calculate_refund(
+    &self,
+    order_id: i64,
+    refund: &CalculateRefund,
+  ) -> ShopifyResult<CalculatedRefund>;
+
+  fn create_refund(&self, order_id: i64, refund: &NewRefund) -> ShopifyResult<Refund>;
+}
+
+impl RefundApi for Client {
+  fn calculate_refund(
+    &self,
+    order_id: i64,
+    refund: &CalculateRefund,
+  ) -> ShopifyResult<CalculatedRefund> {
+    shopify_wrap! {
+      pub struct Res {
+        refund: CalculatedRefund,
+      }
+    }
+    let path = format!(
+      "/admin/api/2023-07/orders/{}/refunds/calculate.json",
+      order_id
+    );
+    let res: Res = self.request(Method::POST, &path, move |b| {
+      b.json(&json!({ "refund": refund }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn create_refund(&self, order_id: i64, refund: &NewRefund) -> ShopifyResult<Refund> {
+    shopify_wrap! {
+      pub struct Res {
+        refund: Refund,
+      }
+    }
+    let path = format!("/admin/api/2023-07/orders/{}/refunds.json", order_id);
+    let res: Res = self.request(Method::POST, &path, move |b| {
+      b.json(&json!({ "refund": refund }))
+    })?;
+    Ok(res.into_inner())
+  }
+}
@@ -0,0 +1,266 @@
+use crate::client::{Client, Method};
+use crate::order::{FinancialStatus, Set, TransactionKind, TransactionStatus};
+use crate::result::*;
+use crate::types::{DateTime, Utc, Value};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Transaction {
+  pub id: i64,
+  pub order_id: Option<i64>,
+  pub kind: TransactionKind,
+  pub status: Option<TransactionStatus>,
+  pub amount: String,
+  pub currency: Option<String>,
+  pub gateway: Option<String>,
+  /// The ID of an associated transaction, e.g. the authorization that a capture settles.
+  pub parent_id: Option<i64>,
+  pub authorization: Option<String>,
+  pub message: Option<String>,
+  pub error_code: Option<String>,
+  pub test: Option<bool>,
+  pub created_at: Option<DateTime<Utc>>,
+  pub processed_at: Option<DateTime<Utc>>,
+  pub location_id: Option<i64>,
+  pub user_id: Option<i64>,
+  pub device_id: Option<i64>,
+  pub source_name: Option<String>,
+  pub payment_id: Option<String>,
+  pub payment_details: Option<Value>,
+  pub receipt: Option<Value>,
+  pub currency_exchange_adjustment: Option<Value>,
+  pub total_unsettled_set: Option<Set>,
+  pub manual_payment_gateway: Option<bool>,
+  pub admin_graphql_api_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NewTransaction {
+  pub kind: TransactionKind,
+  /// Defaults to the full amount of the parent transaction when omitted.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub amount: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub currency: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub parent_id: Option<i64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub gateway: Option<String>,
+  /// Set to `external` to record a payment that was made outside of Shopify.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub source: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub authorization: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub test: Option<bool>,
+}
+
+impl NewTransaction {
+  fn new(kind: TransactionKind) -> Self {
+    NewTransaction {
+      kind,
+      amount: None,
+      currency: None,
+      parent_id: None,
+      gateway: None,
+      source: None,
+      authorization: None,
+      test: None,
+    }
+  }
+
+  /// Captures an authorization. Captures the full authorized amount if `amount` is `None`.
+  pub fn capture(parent_id: i64, amount: Option<&str>) -> Self {
+    NewTransaction {
+      parent_id: Some(parent_id),
+      amount: amount.map(ToOwned::to_owned),
+      ..Self::new(TransactionKind::Capture)
+    }
+  }
+
+  /// Voids an uncaptured authorization.
+  pub fn void(parent_id: i64) -> Self {
+    NewTransaction {
+      parent_id: Some(parent_id),
+      ..Self::new(TransactionKind::Void)
+    }
+  }
+
+  /// Refunds `amount` of a captured transaction.
+  pub fn refund(parent_id: i64, amount: &str) -> Self {
+    NewTransaction {
+      parent_id: Some(parent_id),
+      amount: Some(amount.to_owned()),
+      ..Self::new(TransactionKind::Refund)
+    }
+  }
+
+  /// Records a sale, e.g. an external payment for a pending order.
+  pub fn sale(amount: &str) -> Self {
+    NewTransaction {
+      amount: Some(amount.to_owned()),
+      ..Self::new(TransactionKind::Sale)
+    }
+  }
+
+  pub fn currency(self, currency: &str) -> Self {
+    Self {
+      currency: Some(currency.to_owned()),
+      ..self
+    }
+  }
+}
+
+impl FinancialStatus {
+  /// Derives the financial status of an order totaling `total_price` from its transactions,
+  /// e.g. to check [`Order::financial_status`](crate::order::Order) after creating a transaction.
+  ///
+  /// Only successful transactions are counted. Returns `None` if an amount can not be parsed.
+  pub fn from_transactions(
+    total_price: &str,
+    transactions: &[Transaction],
+  ) -> Option<FinancialStatus> {
+    let (mut authorized, mut paid, mut refunded, mut voided) = (0i64, 0i64, 0i64, false);
+    for t in transactions {
+      if t.status != Some(TransactionStatus::Success) {
+        continue;
+      }
+      let amount = thousandths(&t.amount)?;
+      match t.kind {
+        TransactionKind::Authorization => authorized = authorized.checked_add(amount)?,
+        TransactionKind::Capture | TransactionKind::Sale => paid = paid.checked_add(amount)?,
+        TransactionKind::Refund => refunded = refunded.checked_add(amount)?,
+        TransactionKind::Void => voided = true,
+        TransactionKind::Change | TransactionKind::Other => {}
+      }
+    }
+
+    let status = if paid == 0 {
+      if voided {
+        FinancialStatus::Voided
+      } else if authorized > 0 {
+        FinancialStatus::Authorized
+      } else {
+        FinancialStatus::Pending
+      }
+    } else if refunded >= paid {
+      FinancialStatus::Refunded
+    } else if refunded > 0 {
+      FinancialStatus::PartiallyRefunded
+    } else if paid < thousandths(total_price)? {
+      FinancialStatus::PartiallyPaid
+    } else {
+      FinancialStatus::Paid
+    };
+    Some(status)
+  }
+}
+
+/// Parses a decimal amount into thousandths, so amounts in any currency can be summed exactly.
+fn thousandths(amount: &str) -> Option<i64> {
+  let (sign, amount) = match amount.strip_prefix('-') {
+    Some(amount) => (-1, amount),
+    None => (1, amount),
+  };
+  let (int, frac) = amount.split_once('.').unwrap_or((amount, ""));
+  if frac.len() > 3 || !frac.bytes().all(|b| b.is_ascii_digit()) {
+    return None;
+  }
+  if int.is_empty() || !int.bytes().all(|b| b.is_ascii_digit()) {
+    return None;
+  }
+  let frac = format!("{:0<3}", frac).parse::<i64>().ok()?;
+  int
+    .parse::<i64>()
+    .ok()?
+    .checked_mul(1000)?
+    .checked_add(frac)?
+    .checked_mul(sign)
+}
+
+request_query! {
+  pub struct GetTransactionListParams {
+    pub since_id: Option<i64>,
+    pub fields: Option<Vec<String>>,
+    pub in_shop_currency: Option<bool>,
+  }
+}
+
+pub trait TransactionApi {
+  fn get_transactions(
+    &self,
+    order_id: i64,
+    params: &GetTransactionListParams,
+  ) -> ShopifyResult<Vec<Transaction>>;
+
+  fn get_transaction(&self, order_id: i64, id: i64) -> ShopifyResult<Transaction>;
+
+  fn count_transactions(&self, order_id: i64) -> ShopifyResult<i64>;
+
+  fn create_transaction(
+    &self,
+    order_id: i64,
+    transaction: &NewTransaction,
+  ) -> ShopifyResult<Transaction>;
+}
+
+impl TransactionApi for Client {
+  fn get_transactions(
+    &self,
+    order_id: i64,
+    params: &GetTransactionListParams,
+  ) -> ShopifyResult<Vec<Transaction>> {
+    shopify_wrap! {
+      pub struct Res {
+        transactions: Vec<Transaction>,
+      }
+    }
+    let path = format!("/admin/api/2023-07/orders/{}/transactions.json", order_id);
+    let res: Res = self.request_with_params(Method::GET, &path, params, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn get_transaction(&self, order_id: i64, id: i64) -> ShopifyResult<Transaction> {
+    shopify_wrap! {
+      pub struct Res {
+        transaction: Transaction,
+      }
+    }
+    let path = format!(
+      "/admin/api/2023-07/orders/{}/transactions/{}.json",
+      order_id, id
+    );
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn count_transactions(&self, order_id: i64) -> ShopifyResult<i64> {
+    shopify_wrap! {
+      pub struct Res {
+        count: i64,
+      }
+    }
+    let path = format!(
+      "/admin/api/2023-07/orders/{}/transactions/count.json",
+      order_id
+    );
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn create_transaction(
+    &self,
+    order_id: i64,
+    transaction: &NewTransaction,
+  ) -> ShopifyResult<Transaction> {
+    shopify_wrap! {
+      pub struct Res {
+        transaction: Transaction,
+      }
+    }
+    let path = format!("/admin/api/2023-07/orders/{}/transactions.json", order_id);
+    let res: Res = self.request(Method::POST, &path, move |b| {
+      b.json(&json!({ "transaction": transaction }))
+    })?;
+    Ok(res.into_inner())
+  }
+}
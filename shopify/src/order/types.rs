@@ -1,3 +1,4 @@
+use crate::order::{NewRefund, Refund};
 use crate::types::{DateTime, Utc, Value};
 
 #[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
@@ -21,6 +22,36 @@ pub enum FinancialStatus {
   Voided,
 }
 
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionKind {
+  /// Money that the customer has agreed to pay. The authorization period can be between 7 and 30 days.
+  Authorization,
+  /// A transfer of money that was reserved during the authorization of a shop.
+  Capture,
+  /// The authorization and capture of a payment performed in one single step.
+  Sale,
+  /// The cancellation of a pending authorization or capture.
+  Void,
+  /// The partial or full return of captured money to the customer.
+  Refund,
+  /// Change given to the customer for a cash payment at a point of sale.
+  Change,
+  #[serde(other)]
+  Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionStatus {
+  Pending,
+  Failure,
+  Success,
+  Error,
+  #[serde(other)]
+  Other,
+}
+
 #[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum ShipmentStatus {
@@ -32,7 +63,7 @@ pub enum ShipmentStatus {
   Failure,
   LabelPrinted,
   Delayed,
-  ReadyForPickup
+  ReadyForPickup,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -154,12 +185,22 @@ pub struct Location {
   zip: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscountCodeType {
+  FixedAmount,
+  Percentage,
+  Shipping,
+  #[serde(other)]
+  Other,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DiscountCode {
   pub amount: String,
   pub code: String,
   #[serde(rename = "type")]
-  pub type_: String,
+  pub type_: DiscountCodeType,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -223,7 +264,7 @@ pub struct Order {
   pub shipping_address: Option<Address>,
   pub fulfillments: Vec<OrderFulfillment>,
   pub client_details: Option<ClientDetails>,
-  pub refunds: Vec<Value>,
+  pub refunds: Vec<Refund>,
   pub customer: Option<Customer>,
 }
 
@@ -280,6 +321,91 @@ pub struct OrderRisk {
   pub source: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CancelReason {
+  /// The customer canceled the order.
+  Customer,
+  /// The order was fraudulent.
+  Fraud,
+  /// Items in the order were not in inventory.
+  Inventory,
+  /// The payment was declined.
+  Declined,
+  Other,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct CancelOrderParams {
+  /// The amount to refund. If set, Shopify attempts to void or refund the payment, depending on its status.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub amount: Option<String>,
+  /// The currency of the refund. Required for multi-currency orders whenever `amount` is set.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub currency: Option<String>,
+  /// Whether to send an email to the customer notifying them of the cancellation.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub email: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub reason: Option<CancelReason>,
+  /// Whether to restock refunded items back to your store's inventory.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub restock: Option<bool>,
+  /// The refund transactions to perform. Required for some more complex refund situations.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub refund: Option<NewRefund>,
+}
+
+/// Newer API versions cancel orders asynchronously and only return a notice and a job.
+#[derive(Debug)]
+pub enum CancelOrderResponse {
+  Cancelled {
+    order: Box<Order>,
+  },
+  Scheduled {
+    notice: String,
+    job: Option<CancelOrderJob>,
+  },
+}
+
+/// The job that cancels the order asynchronously.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CancelOrderJob {
+  pub id: i64,
+  pub done: bool,
+}
+
+impl<'de> serde::Deserialize<'de> for CancelOrderResponse {
+  fn deserialize<D>(d: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    use serde::de::Error;
+
+    #[derive(Deserialize)]
+    struct Raw {
+      order: Option<Value>,
+      notice: Option<String>,
+      job: Option<CancelOrderJob>,
+    }
+
+    // Matched by key, so that an order that fails to decode reports its own error.
+    match Raw::deserialize(d)? {
+      Raw {
+        order: Some(order), ..
+      } => serde_json::from_value(order)
+        .map(|order| CancelOrderResponse::Cancelled { order })
+        .map_err(D::Error::custom),
+      Raw {
+        notice: Some(notice),
+        job,
+        ..
+      } => Ok(CancelOrderResponse::Scheduled { notice, job }),
+      _ => Err(D::Error::missing_field("order")),
+    }
+  }
+}
+
 #[derive(Debug, Serialize)]
 pub struct OrderUpdateParams {
   pub buyer_accepts_marketing: Option<bool>,
@@ -0,0 +1,161 @@
+use crate::client::{Client, Method};
+use crate::pagination::{GetPage, Paginated};
+use crate::result::*;
+use crate::types::{DateTime, Utc};
+use serde::Serialize;
+
+mod types;
+pub use self::types::*;
+
+request_query! {
+  pub struct GetPageListParams {
+    pub limit: Option<i64>,
+    pub since_id: Option<i64>,
+    pub title: Option<String>,
+    pub handle: Option<String>,
+    pub created_at_min: Option<DateTime<Utc>>,
+    pub created_at_max: Option<DateTime<Utc>>,
+    pub updated_at_min: Option<DateTime<Utc>>,
+    pub updated_at_max: Option<DateTime<Utc>>,
+    pub published_status: Option<String>,
+    pub fields: Option<Vec<String>>,
+  }
+}
+
+pub trait PageApi {
+  fn list(&self, params: &GetPageListParams) -> ShopifyResult<Paginated<Vec<Page>>>;
+
+  fn list_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<Page>>>;
+
+  fn get(&self, id: i64) -> ShopifyResult<Page>;
+
+  fn create(&self, page: &NewPage) -> ShopifyResult<Page>;
+
+  fn update<V: Serialize>(&self, id: i64, value: V) -> ShopifyResult<Page>;
+
+  fn delete(&self, id: i64) -> ShopifyResult<()>;
+}
+
+impl PageApi for Client {
+  fn list(&self, params: &GetPageListParams) -> ShopifyResult<Paginated<Vec<Page>>> {
+    shopify_wrap! {
+      pub struct Res {
+        pages: Vec<Page>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/pages.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<Page>>> {
+    shopify_wrap! {
+      pub struct Res {
+        pages: Vec<Page>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/pages.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn get(&self, id: i64) -> ShopifyResult<Page> {
+    shopify_wrap! {
+      pub struct Res {
+        page: Page,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/pages/{}.json", id);
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn create(&self, page: &NewPage) -> ShopifyResult<Page> {
+    shopify_wrap! {
+      pub struct Res {
+        page: Page,
+      }
+    }
+
+    let res: Res = self.request(Method::POST, "/admin/api/2023-07/pages.json", move |b| {
+      b.json(&json!({ "page": page }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn update<V: Serialize>(&self, id: i64, value: V) -> ShopifyResult<Page> {
+    shopify_wrap! {
+      pub struct Res {
+        page: Page,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/pages/{}.json", id);
+    let res: Res = self.request(Method::PUT, &path, move |b| {
+      b.json(&json!({ "page": value }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn delete(&self, id: i64) -> ShopifyResult<()> {
+    let path = format!("/admin/api/2023-07/pages/{}.json", id);
+    self.request_no_content(Method::DELETE, &path, std::convert::identity)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_deserialize_page() {
+    shopify_wrap! {
+      pub struct Res {
+        page: Page,
+      }
+    }
+
+    let page = serde_json::from_str::<Res>(
+      r##"
+    {
+      "page": {
+        "id": 131092082,
+        "title": "Terms of Services",
+        "shop_id": 548380009,
+        "handle": "tos",
+        "body_html": "<p>We make <strong>perfect</strong> stuff, we don't need a warranty.</p>",
+        "author": "Dennis",
+        "created_at": "2008-07-15T20:00:00-04:00",
+        "updated_at": "2008-07-16T20:00:00-04:00",
+        "published_at": null,
+        "template_suffix": null,
+        "admin_graphql_api_id": "gid://shopify/OnlineStorePage/131092082"
+      }
+    }
+    "##,
+    )
+    .unwrap()
+    .into_inner();
+    assert_eq!(page.handle, "tos");
+    assert!(page.published_at.is_none());
+  }
+
+  #[test]
+  #[ignore]
+  fn test_list_pages() {
+    let client = crate::client::get_test_client();
+    let page = client.list(&Default::default()).unwrap();
+    println!("{:#?}", page);
+  }
+}
@@ -0,0 +1,32 @@
+use crate::types::{DateTime, Utc};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Page {
+  pub id: i64,
+  pub shop_id: Option<i64>,
+  pub title: String,
+  pub handle: String,
+  pub body_html: Option<String>,
+  pub author: Option<String>,
+  pub template_suffix: Option<String>,
+  /// `None` if the page is hidden.
+  pub published_at: Option<DateTime<Utc>>,
+  pub created_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct NewPage {
+  pub title: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub handle: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub body_html: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub author: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub template_suffix: Option<String>,
+  /// Pages are published when created unless this is `false`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub published: Option<bool>,
+}
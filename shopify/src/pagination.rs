@@ -35,6 +35,14 @@ impl ShopifyRequestQuery for GetPage {
   }
 }
 
+/// The `pageInfo` of a GraphQL connection.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PageInfo {
+  pub has_next_page: bool,
+  pub end_cursor: Option<String>,
+}
+
 #[derive(Debug)]
 pub struct Paginated<T> {
   pub payload: T,
@@ -71,6 +79,22 @@ impl<T> Paginated<T> {
     })
   }
 
+  /// Uses the end cursor of a GraphQL connection as the next `page_info`.
+  ///
+  /// GraphQL pages are always fetched forwards (`first`/`after`), so `previous_page_info` is
+  /// `None`: passing a start cursor as `after` would return the following page instead.
+  pub(crate) fn from_page_info(payload: T, page_info: PageInfo) -> Paginated<T> {
+    let PageInfo {
+      has_next_page,
+      end_cursor,
+    } = page_info;
+    Paginated {
+      payload,
+      previous_page_info: None,
+      next_page_info: end_cursor.filter(|_| has_next_page),
+    }
+  }
+
   pub fn map<F, R>(self, f: F) -> Paginated<R>
   where
     F: FnOnce(T) -> R,
@@ -97,6 +121,54 @@ impl<T> Paginated<T> {
   }
 }
 
+impl<T> Paginated<Vec<T>> {
+  /// Turns this page into an iterator over every item of every following page.
+  ///
+  /// `fetch` is called with the [`GetPage`] of the next page whenever the current page is exhausted.
+  pub fn into_iter_with<'a, F>(self, fetch: F) -> PaginatedIter<'a, T>
+  where
+    F: FnMut(&GetPage) -> ShopifyResult<Paginated<Vec<T>>> + 'a,
+  {
+    PaginatedIter {
+      next: self.get_next(),
+      items: self.payload.into_iter(),
+      fetch: Box::new(fetch),
+    }
+  }
+}
+
+type FetchPage<'a, T> = Box<dyn FnMut(&GetPage) -> ShopifyResult<Paginated<Vec<T>>> + 'a>;
+
+/// An iterator that transparently follows `page_info` cursors.
+///
+/// Yields an error and stops if fetching a page fails.
+pub struct PaginatedIter<'a, T> {
+  items: std::vec::IntoIter<T>,
+  next: Option<GetPage>,
+  fetch: FetchPage<'a, T>,
+}
+
+impl<'a, T> Iterator for PaginatedIter<'a, T> {
+  type Item = ShopifyResult<T>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    loop {
+      if let Some(item) = self.items.next() {
+        return Some(Ok(item));
+      }
+
+      let page = self.next.take()?;
+      match (self.fetch)(&page) {
+        Ok(res) => {
+          self.next = res.get_next();
+          self.items = res.payload.into_iter();
+        }
+        Err(err) => return Some(Err(err)),
+      }
+    }
+  }
+}
+
 fn parse_page_info(url: &str) -> ShopifyResult<String> {
   let parsed = Url::parse(url).map_err(ShopifyError::UrlParse)?;
   let value = parsed
@@ -106,3 +178,56 @@ fn parse_page_info(url: &str) -> ShopifyResult<String> {
     .ok_or_else(|| ShopifyError::PageInfoNotPresent)?;
   Ok(value)
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn page(payload: Vec<i64>, next: Option<&str>) -> Paginated<Vec<i64>> {
+    Paginated {
+      payload,
+      previous_page_info: None,
+      next_page_info: next.map(ToString::to_string),
+    }
+  }
+
+  #[test]
+  fn test_paginated_iter() {
+    let mut requested = vec![];
+    let items = page(vec![1, 2], Some("p2"))
+      .into_iter_with(|params| {
+        requested.push(params.page_info.clone());
+        Ok(match params.page_info.as_str() {
+          "p2" => page(vec![], Some("p3")),
+          "p3" => page(vec![3], None),
+          _ => unreachable!(),
+        })
+      })
+      .collect::<ShopifyResult<Vec<_>>>()
+      .unwrap();
+    assert_eq!(items, vec![1, 2, 3]);
+    assert_eq!(requested, vec!["p2", "p3"]);
+  }
+
+  #[test]
+  fn test_paginated_iter_error() {
+    let mut iter = page(vec![1], Some("p2")).into_iter_with(|_| Err(ShopifyError::NotFound));
+    assert!(matches!(iter.next(), Some(Ok(1))));
+    assert!(matches!(iter.next(), Some(Err(ShopifyError::NotFound))));
+    assert!(iter.next().is_none());
+  }
+
+  #[test]
+  fn test_from_page_info() {
+    let page_info: PageInfo = serde_json::from_value(serde_json::json!({
+      "hasNextPage": true,
+      "hasPreviousPage": true,
+      "startCursor": "start",
+      "endCursor": "end",
+    }))
+    .unwrap();
+    let page = Paginated::from_page_info(vec![1], page_info);
+    assert_eq!(page.next_page_info.as_deref(), Some("end"));
+    assert!(page.get_previous().is_none());
+  }
+}
@@ -0,0 +1,569 @@
+use crate::client::{Client, Method};
+use crate::pagination::{GetPage, Paginated};
+use crate::result::*;
+use crate::types::{DateTime, Utc};
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+mod types;
+pub use self::types::*;
+
+/// The maximum number of codes of a single [`DiscountCodeCreationJob`].
+pub const DISCOUNT_CODE_BATCH_SIZE: usize = 100;
+
+request_query! {
+  pub struct GetPriceRuleListParams {
+    pub limit: Option<i64>,
+    pub since_id: Option<i64>,
+    pub created_at_min: Option<DateTime<Utc>>,
+    pub created_at_max: Option<DateTime<Utc>>,
+    pub updated_at_min: Option<DateTime<Utc>>,
+    pub updated_at_max: Option<DateTime<Utc>>,
+    pub starts_at_min: Option<DateTime<Utc>>,
+    pub starts_at_max: Option<DateTime<Utc>>,
+    pub ends_at_min: Option<DateTime<Utc>>,
+    pub ends_at_max: Option<DateTime<Utc>>,
+    pub times_used: Option<i64>,
+  }
+}
+
+request_query! {
+  pub struct GetDiscountCodeListParams {
+    pub limit: Option<i64>,
+  }
+}
+
+pub trait PriceRuleApi {
+  fn list_price_rules(
+    &self,
+    params: &GetPriceRuleListParams,
+  ) -> ShopifyResult<Paginated<Vec<PriceRule>>>;
+
+  fn list_price_rules_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<PriceRule>>>;
+
+  fn get_price_rule(&self, id: i64) -> ShopifyResult<PriceRule>;
+
+  fn create_price_rule(&self, price_rule: &NewPriceRule) -> ShopifyResult<PriceRule>;
+
+  fn update_price_rule<V: Serialize>(&self, id: i64, value: V) -> ShopifyResult<PriceRule>;
+
+  /// Deletes the price rule and all of its discount codes.
+  fn delete_price_rule(&self, id: i64) -> ShopifyResult<()>;
+}
+
+impl PriceRuleApi for Client {
+  fn list_price_rules(
+    &self,
+    params: &GetPriceRuleListParams,
+  ) -> ShopifyResult<Paginated<Vec<PriceRule>>> {
+    shopify_wrap! {
+      pub struct Res {
+        price_rules: Vec<PriceRule>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/price_rules.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_price_rules_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<PriceRule>>> {
+    shopify_wrap! {
+      pub struct Res {
+        price_rules: Vec<PriceRule>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/price_rules.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn get_price_rule(&self, id: i64) -> ShopifyResult<PriceRule> {
+    shopify_wrap! {
+      pub struct Res {
+        price_rule: PriceRule,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/price_rules/{}.json", id);
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn create_price_rule(&self, price_rule: &NewPriceRule) -> ShopifyResult<PriceRule> {
+    shopify_wrap! {
+      pub struct Res {
+        price_rule: PriceRule,
+      }
+    }
+
+    let res: Res = self.request(
+      Method::POST,
+      "/admin/api/2023-07/price_rules.json",
+      move |b| b.json(&json!({ "price_rule": price_rule })),
+    )?;
+    Ok(res.into_inner())
+  }
+
+  fn update_price_rule<V: Serialize>(&self, id: i64, value: V) -> ShopifyResult<PriceRule> {
+    shopify_wrap! {
+      pub struct Res {
+        price_rule: PriceRule,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/price_rules/{}.json", id);
+    let res: Res = self.request(Method::PUT, &path, move |b| {
+      b.json(&json!({ "price_rule": value }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn delete_price_rule(&self, id: i64) -> ShopifyResult<()> {
+    let path = format!("/admin/api/2023-07/price_rules/{}.json", id);
+    self.request_no_content(Method::DELETE, &path, std::convert::identity)
+  }
+}
+
+pub trait DiscountCodeApi {
+  fn list_discount_codes(
+    &self,
+    price_rule_id: i64,
+    params: &GetDiscountCodeListParams,
+  ) -> ShopifyResult<Paginated<Vec<DiscountCode>>>;
+
+  fn list_discount_codes_page(
+    &self,
+    price_rule_id: i64,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<DiscountCode>>>;
+
+  fn get_discount_code(&self, price_rule_id: i64, id: i64) -> ShopifyResult<DiscountCode>;
+
+  /// Finds a discount code of any price rule by its code.
+  fn lookup_discount_code(&self, code: &str) -> ShopifyResult<DiscountCode>;
+
+  fn create_discount_code(&self, price_rule_id: i64, code: &str) -> ShopifyResult<DiscountCode>;
+
+  fn update_discount_code(
+    &self,
+    price_rule_id: i64,
+    id: i64,
+    code: &str,
+  ) -> ShopifyResult<DiscountCode>;
+
+  fn delete_discount_code(&self, price_rule_id: i64, id: i64) -> ShopifyResult<()>;
+
+  /// Starts a job creating up to [`DISCOUNT_CODE_BATCH_SIZE`] discount codes.
+  fn create_discount_code_batch(
+    &self,
+    price_rule_id: i64,
+    codes: &[&str],
+  ) -> ShopifyResult<DiscountCodeCreationJob>;
+
+  fn get_discount_code_batch(
+    &self,
+    price_rule_id: i64,
+    batch_id: i64,
+  ) -> ShopifyResult<DiscountCodeCreationJob>;
+
+  /// Polls the job every `interval` until it is completed.
+  ///
+  /// Fails with [`ShopifyError::DiscountCodeBatchTimeout`] if the job is not completed after
+  /// `timeout`.
+  fn wait_discount_code_batch(
+    &self,
+    price_rule_id: i64,
+    batch_id: i64,
+    interval: Duration,
+    timeout: Duration,
+  ) -> ShopifyResult<DiscountCodeCreationJob>;
+
+  /// Returns the created codes and the codes that failed with their errors.
+  fn get_discount_code_batch_results(
+    &self,
+    price_rule_id: i64,
+    batch_id: i64,
+  ) -> ShopifyResult<Vec<DiscountCodeCreationResult>>;
+
+  /// Creates any number of codes, running one creation job per [`DISCOUNT_CODE_BATCH_SIZE`] codes
+  /// and waiting up to `timeout` for each to complete.
+  fn create_discount_codes(
+    &self,
+    price_rule_id: i64,
+    codes: &[&str],
+    interval: Duration,
+    timeout: Duration,
+  ) -> ShopifyResult<Vec<DiscountCodeCreationResult>>;
+}
+
+impl DiscountCodeApi for Client {
+  fn list_discount_codes(
+    &self,
+    price_rule_id: i64,
+    params: &GetDiscountCodeListParams,
+  ) -> ShopifyResult<Paginated<Vec<DiscountCode>>> {
+    shopify_wrap! {
+      pub struct Res {
+        discount_codes: Vec<DiscountCode>,
+      }
+    }
+
+    let path = format!(
+      "/admin/api/2023-07/price_rules/{}/discount_codes.json",
+      price_rule_id
+    );
+    let res: Paginated<Res> =
+      self.request_with_params_paginated(Method::GET, &path, params, std::convert::identity)?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_discount_codes_page(
+    &self,
+    price_rule_id: i64,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<DiscountCode>>> {
+    shopify_wrap! {
+      pub struct Res {
+        discount_codes: Vec<DiscountCode>,
+      }
+    }
+
+    let path = format!(
+      "/admin/api/2023-07/price_rules/{}/discount_codes.json",
+      price_rule_id
+    );
+    let res: Paginated<Res> =
+      self.request_with_params_paginated(Method::GET, &path, params, std::convert::identity)?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn get_discount_code(&self, price_rule_id: i64, id: i64) -> ShopifyResult<DiscountCode> {
+    shopify_wrap! {
+      pub struct Res {
+        discount_code: DiscountCode,
+      }
+    }
+
+    let path = format!(
+      "/admin/api/2023-07/price_rules/{}/discount_codes/{}.json",
+      price_rule_id, id
+    );
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn lookup_discount_code(&self, code: &str) -> ShopifyResult<DiscountCode> {
+    shopify_wrap! {
+      pub struct Res {
+        discount_code: DiscountCode,
+      }
+    }
+
+    // Redirects to the discount code.
+    let res: Res = self.request_with_params(
+      Method::GET,
+      "/admin/api/2023-07/discount_codes/lookup.json",
+      &("code", code),
+      std::convert::identity,
+    )?;
+    Ok(res.into_inner())
+  }
+
+  fn create_discount_code(&self, price_rule_id: i64, code: &str) -> ShopifyResult<DiscountCode> {
+    shopify_wrap! {
+      pub struct Res {
+        discount_code: DiscountCode,
+      }
+    }
+
+    let path = format!(
+      "/admin/api/2023-07/price_rules/{}/discount_codes.json",
+      price_rule_id
+    );
+    let res: Res = self.request(Method::POST, &path, |b| {
+      b.json(&json!({ "discount_code": { "code": code } }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn update_discount_code(
+    &self,
+    price_rule_id: i64,
+    id: i64,
+    code: &str,
+  ) -> ShopifyResult<DiscountCode> {
+    shopify_wrap! {
+      pub struct Res {
+        discount_code: DiscountCode,
+      }
+    }
+
+    let path = format!(
+      "/admin/api/2023-07/price_rules/{}/discount_codes/{}.json",
+      price_rule_id, id
+    );
+    let res: Res = self.request(Method::PUT, &path, |b| {
+      b.json(&json!({ "discount_code": { "id": id, "code": code } }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn delete_discount_code(&self, price_rule_id: i64, id: i64) -> ShopifyResult<()> {
+    let path = format!(
+      "/admin/api/2023-07/price_rules/{}/discount_codes/{}.json",
+      price_rule_id, id
+    );
+    self.request_no_content(Method::DELETE, &path, std::convert::identity)
+  }
+
+  fn create_discount_code_batch(
+    &self,
+    price_rule_id: i64,
+    codes: &[&str],
+  ) -> ShopifyResult<DiscountCodeCreationJob> {
+    shopify_wrap! {
+      pub struct Res {
+        discount_code_creation: DiscountCodeCreationJob,
+      }
+    }
+
+    let path = format!(
+      "/admin/api/2023-07/price_rules/{}/batch.json",
+      price_rule_id
+    );
+    let codes: Vec<_> = codes.iter().map(|code| json!({ "code": code })).collect();
+    let res: Res = self.request(Method::POST, &path, |b| {
+      b.json(&json!({ "discount_codes": codes }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn get_discount_code_batch(
+    &self,
+    price_rule_id: i64,
+    batch_id: i64,
+  ) -> ShopifyResult<DiscountCodeCreationJob> {
+    shopify_wrap! {
+      pub struct Res {
+        discount_code_creation: DiscountCodeCreationJob,
+      }
+    }
+
+    let path = format!(
+      "/admin/api/2023-07/price_rules/{}/batch/{}.json",
+      price_rule_id, batch_id
+    );
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn wait_discount_code_batch(
+    &self,
+    price_rule_id: i64,
+    batch_id: i64,
+    interval: Duration,
+    timeout: Duration,
+  ) -> ShopifyResult<DiscountCodeCreationJob> {
+    let started = Instant::now();
+    loop {
+      let job = self.get_discount_code_batch(price_rule_id, batch_id)?;
+      if job.status == DiscountCodeCreationJobStatus::Completed {
+        return Ok(job);
+      }
+      if started.elapsed() + interval > timeout {
+        return Err(ShopifyError::DiscountCodeBatchTimeout {
+          batch_id,
+          status: job.status,
+        });
+      }
+      std::thread::sleep(interval);
+    }
+  }
+
+  fn get_discount_code_batch_results(
+    &self,
+    price_rule_id: i64,
+    batch_id: i64,
+  ) -> ShopifyResult<Vec<DiscountCodeCreationResult>> {
+    shopify_wrap! {
+      pub struct Res {
+        discount_codes: Vec<DiscountCodeCreationResult>,
+      }
+    }
+
+    let path = format!(
+      "/admin/api/2023-07/price_rules/{}/batch/{}/discount_codes.json",
+      price_rule_id, batch_id
+    );
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn create_discount_codes(
+    &self,
+    price_rule_id: i64,
+    codes: &[&str],
+    interval: Duration,
+    timeout: Duration,
+  ) -> ShopifyResult<Vec<DiscountCodeCreationResult>> {
+    let mut results = Vec::with_capacity(codes.len());
+    for chunk in codes.chunks(DISCOUNT_CODE_BATCH_SIZE) {
+      let job = self.create_discount_code_batch(price_rule_id, chunk)?;
+      self.wait_discount_code_batch(price_rule_id, job.id, interval, timeout)?;
+      results.extend(self.get_discount_code_batch_results(price_rule_id, job.id)?);
+    }
+    Ok(results)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::TimeZone;
+
+  #[test]
+  fn test_deserialize_price_rule() {
+    shopify_wrap! {
+      pub struct Res {
+        price_rule: PriceRule,
+      }
+    }
+
+    let price_rule = serde_json::from_str::<Res>(
+      r##"
+    {
+      "price_rule": {
+        "id": 507328175,
+        "value_type": "fixed_amount",
+        "value": "-10.0",
+        "customer_selection": "all",
+        "target_type": "line_item",
+        "target_selection": "all",
+        "allocation_method": "across",
+        "allocation_limit": null,
+        "once_per_customer": false,
+        "usage_limit": null,
+        "starts_at": "2023-07-05T18:33:22-04:00",
+        "ends_at": "2023-07-17T18:33:22-04:00",
+        "created_at": "2023-07-11T18:33:22-04:00",
+        "updated_at": "2023-07-11T18:33:22-04:00",
+        "entitled_product_ids": [],
+        "entitled_variant_ids": [],
+        "entitled_collection_ids": [],
+        "entitled_country_ids": [],
+        "prerequisite_product_ids": [],
+        "prerequisite_variant_ids": [],
+        "prerequisite_collection_ids": [],
+        "customer_segment_prerequisite_ids": [],
+        "prerequisite_customer_ids": [],
+        "prerequisite_subtotal_range": null,
+        "prerequisite_quantity_range": null,
+        "prerequisite_shipping_price_range": null,
+        "prerequisite_to_entitlement_quantity_ratio": {
+          "prerequisite_quantity": null,
+          "entitled_quantity": null
+        },
+        "prerequisite_to_entitlement_purchase": {
+          "prerequisite_amount": null
+        },
+        "title": "SUMMERSALE10OFF",
+        "admin_graphql_api_id": "gid://shopify/PriceRule/507328175"
+      }
+    }
+    "##,
+    )
+    .unwrap()
+    .into_inner();
+    assert_eq!(price_rule.value_type, DiscountValueType::FixedAmount);
+    assert_eq!(
+      price_rule.allocation_method,
+      PriceRuleAllocationMethod::Across
+    );
+  }
+
+  #[test]
+  fn test_serialize_new_price_rule() {
+    let mut price_rule = NewPriceRule::new(
+      "SPRING",
+      DiscountValueType::Percentage,
+      "-15.0",
+      Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap(),
+    );
+    price_rule.once_per_customer = Some(true);
+    price_rule.entitled_collection_ids = vec![841564295];
+    price_rule.target_selection = PriceRuleTargetSelection::Entitled;
+    assert_eq!(
+      serde_json::to_value(price_rule).unwrap(),
+      serde_json::json!({
+        "title": "SPRING",
+        "value_type": "percentage",
+        "value": "-15.0",
+        "target_type": "line_item",
+        "target_selection": "entitled",
+        "allocation_method": "across",
+        "customer_selection": "all",
+        "starts_at": "2023-03-01T00:00:00Z",
+        "once_per_customer": true,
+        "entitled_collection_ids": [841564295]
+      })
+    );
+  }
+
+  #[test]
+  fn test_deserialize_discount_code_creation() {
+    shopify_wrap! {
+      pub struct Res {
+        discount_codes: Vec<DiscountCodeCreationResult>,
+      }
+    }
+
+    let results = serde_json::from_str::<Res>(
+      r##"
+    {
+      "discount_codes": [
+        { "id": 1054381139, "code": "SUMMER1", "errors": {} },
+        { "id": null, "code": "SUMMER1", "errors": { "code": ["must be unique"] } }
+      ]
+    }
+    "##,
+    )
+    .unwrap()
+    .into_inner();
+    assert!(results[0].is_created());
+    assert!(!results[1].is_created());
+    assert_eq!(results[1].errors["code"][0], "must be unique");
+  }
+
+  #[test]
+  fn test_deserialize_discount_code_creation_job() {
+    let job: DiscountCodeCreationJob = serde_json::from_value(serde_json::json!({
+      "id": 173232803,
+      "price_rule_id": 507328175,
+      "status": "failed",
+      "codes_count": 3,
+      "imported_count": 0,
+      "failed_count": 0,
+      "logs": []
+    }))
+    .unwrap();
+    assert_eq!(job.status, DiscountCodeCreationJobStatus::Other);
+  }
+
+  #[test]
+  #[ignore]
+  fn test_list_price_rules() {
+    let client = crate::client::get_test_client();
+    let page = client.list_price_rules(&Default::default()).unwrap();
+    println!("{:#?}", page);
+  }
+}
@@ -0,0 +1,225 @@
+pub use crate::draft_order::DiscountValueType;
+use crate::types::{DateTime, Utc, Value};
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceRuleTargetType {
+  LineItem,
+  ShippingLine,
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceRuleTargetSelection {
+  /// The price rule applies to all line items or shipping lines.
+  All,
+  /// The price rule applies to the `entitled_*` items only.
+  Entitled,
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceRuleAllocationMethod {
+  /// The discount is applied to each of the entitled items.
+  Each,
+  /// The discount is divided evenly across the entitled items.
+  Across,
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceRuleCustomerSelection {
+  All,
+  /// Only customers in `prerequisite_customer_ids` are eligible.
+  Prerequisite,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
+pub struct PrerequisiteRange {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub greater_than_or_equal_to: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub less_than_or_equal_to: Option<String>,
+}
+
+/// The buy X get Y ratio.
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
+pub struct PrerequisiteToEntitlementQuantityRatio {
+  pub prerequisite_quantity: Option<i64>,
+  pub entitled_quantity: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PriceRule {
+  pub id: i64,
+  pub title: String,
+  pub value_type: DiscountValueType,
+  /// A negative amount or percentage, e.g. `-10.0`.
+  pub value: String,
+  pub target_type: PriceRuleTargetType,
+  pub target_selection: PriceRuleTargetSelection,
+  pub allocation_method: PriceRuleAllocationMethod,
+  pub allocation_limit: Option<i64>,
+  pub customer_selection: PriceRuleCustomerSelection,
+  pub once_per_customer: bool,
+  pub usage_limit: Option<i64>,
+  pub starts_at: DateTime<Utc>,
+  pub ends_at: Option<DateTime<Utc>>,
+  pub created_at: Option<DateTime<Utc>>,
+  pub updated_at: Option<DateTime<Utc>>,
+  #[serde(default)]
+  pub entitled_product_ids: Vec<i64>,
+  #[serde(default)]
+  pub entitled_variant_ids: Vec<i64>,
+  #[serde(default)]
+  pub entitled_collection_ids: Vec<i64>,
+  #[serde(default)]
+  pub entitled_country_ids: Vec<i64>,
+  #[serde(default)]
+  pub prerequisite_product_ids: Vec<i64>,
+  #[serde(default)]
+  pub prerequisite_variant_ids: Vec<i64>,
+  #[serde(default)]
+  pub prerequisite_collection_ids: Vec<i64>,
+  #[serde(default)]
+  pub prerequisite_customer_ids: Vec<i64>,
+  pub prerequisite_subtotal_range: Option<PrerequisiteRange>,
+  pub prerequisite_quantity_range: Option<PrerequisiteRange>,
+  pub prerequisite_shipping_price_range: Option<PrerequisiteRange>,
+  pub prerequisite_to_entitlement_quantity_ratio: Option<PrerequisiteToEntitlementQuantityRatio>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NewPriceRule {
+  pub title: String,
+  pub value_type: DiscountValueType,
+  pub value: String,
+  pub target_type: PriceRuleTargetType,
+  pub target_selection: PriceRuleTargetSelection,
+  pub allocation_method: PriceRuleAllocationMethod,
+  pub customer_selection: PriceRuleCustomerSelection,
+  pub starts_at: DateTime<Utc>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub ends_at: Option<DateTime<Utc>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub allocation_limit: Option<i64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub once_per_customer: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub usage_limit: Option<i64>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub entitled_product_ids: Vec<i64>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub entitled_variant_ids: Vec<i64>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub entitled_collection_ids: Vec<i64>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub entitled_country_ids: Vec<i64>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub prerequisite_product_ids: Vec<i64>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub prerequisite_variant_ids: Vec<i64>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub prerequisite_collection_ids: Vec<i64>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub prerequisite_customer_ids: Vec<i64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub prerequisite_subtotal_range: Option<PrerequisiteRange>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub prerequisite_quantity_range: Option<PrerequisiteRange>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub prerequisite_shipping_price_range: Option<PrerequisiteRange>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub prerequisite_to_entitlement_quantity_ratio: Option<PrerequisiteToEntitlementQuantityRatio>,
+}
+
+impl NewPriceRule {
+  /// A price rule for a discount on all line items, usable by all customers.
+  pub fn new(
+    title: &str,
+    value_type: DiscountValueType,
+    value: &str,
+    starts_at: DateTime<Utc>,
+  ) -> Self {
+    NewPriceRule {
+      title: title.to_owned(),
+      value_type,
+      value: value.to_owned(),
+      target_type: PriceRuleTargetType::LineItem,
+      target_selection: PriceRuleTargetSelection::All,
+      allocation_method: PriceRuleAllocationMethod::Across,
+      customer_selection: PriceRuleCustomerSelection::All,
+      starts_at,
+      ends_at: None,
+      allocation_limit: None,
+      once_per_customer: None,
+      usage_limit: None,
+      entitled_product_ids: vec![],
+      entitled_variant_ids: vec![],
+      entitled_collection_ids: vec![],
+      entitled_country_ids: vec![],
+      prerequisite_product_ids: vec![],
+      prerequisite_variant_ids: vec![],
+      prerequisite_collection_ids: vec![],
+      prerequisite_customer_ids: vec![],
+      prerequisite_subtotal_range: None,
+      prerequisite_quantity_range: None,
+      prerequisite_shipping_price_range: None,
+      prerequisite_to_entitlement_quantity_ratio: None,
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DiscountCode {
+  pub id: i64,
+  pub price_rule_id: i64,
+  pub code: String,
+  pub usage_count: Option<i64>,
+  pub created_at: Option<DateTime<Utc>>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscountCodeCreationJobStatus {
+  Queued,
+  Running,
+  Completed,
+  #[serde(other)]
+  Other,
+}
+
+/// An asynchronous job creating up to 100 discount codes.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DiscountCodeCreationJob {
+  pub id: i64,
+  pub price_rule_id: i64,
+  pub status: DiscountCodeCreationJobStatus,
+  pub codes_count: i64,
+  pub imported_count: i64,
+  pub failed_count: i64,
+  #[serde(default)]
+  pub logs: Vec<Value>,
+  pub started_at: Option<DateTime<Utc>>,
+  pub completed_at: Option<DateTime<Utc>>,
+  pub created_at: Option<DateTime<Utc>>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// The outcome of creating one code of a [`DiscountCodeCreationJob`].
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DiscountCodeCreationResult {
+  /// `None` if the code couldn't be created.
+  pub id: Option<i64>,
+  pub code: String,
+  /// Validation errors keyed by field, e.g. `{"code": ["must be unique"]}`.
+  #[serde(default)]
+  pub errors: Value,
+}
+
+impl DiscountCodeCreationResult {
+  pub fn is_created(&self) -> bool {
+    self.id.is_some()
+  }
+}
@@ -16,6 +16,8 @@ pub struct Product {
   pub template_suffix: Value,
   pub tags: String,
   pub published_scope: String,
+  /// Not included when listing the products of a collection.
+  #[serde(default)]
   pub variants: Vec<Variant>,
   pub options: Vec<ProductOption>,
   pub images: Vec<Image>,
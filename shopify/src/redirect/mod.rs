@@ -0,0 +1,124 @@
+use crate::client::{Client, Method};
+use crate::pagination::{GetPage, Paginated};
+use crate::result::*;
+use serde::Serialize;
+
+mod types;
+pub use self::types::*;
+
+request_query! {
+  pub struct GetRedirectListParams {
+    pub limit: Option<i64>,
+    pub since_id: Option<i64>,
+    pub path: Option<String>,
+    pub target: Option<String>,
+    pub fields: Option<Vec<String>>,
+  }
+}
+
+pub trait RedirectApi {
+  fn list(&self, params: &GetRedirectListParams) -> ShopifyResult<Paginated<Vec<Redirect>>>;
+
+  fn list_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<Redirect>>>;
+
+  fn get(&self, id: i64) -> ShopifyResult<Redirect>;
+
+  fn create(&self, redirect: &NewRedirect) -> ShopifyResult<Redirect>;
+
+  fn update<V: Serialize>(&self, id: i64, value: V) -> ShopifyResult<Redirect>;
+
+  fn delete(&self, id: i64) -> ShopifyResult<()>;
+}
+
+impl RedirectApi for Client {
+  fn list(&self, params: &GetRedirectListParams) -> ShopifyResult<Paginated<Vec<Redirect>>> {
+    shopify_wrap! {
+      pub struct Res {
+        redirects: Vec<Redirect>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/redirects.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<Redirect>>> {
+    shopify_wrap! {
+      pub struct Res {
+        redirects: Vec<Redirect>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/redirects.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn get(&self, id: i64) -> ShopifyResult<Redirect> {
+    shopify_wrap! {
+      pub struct Res {
+        redirect: Redirect,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/redirects/{}.json", id);
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn create(&self, redirect: &NewRedirect) -> ShopifyResult<Redirect> {
+    shopify_wrap! {
+      pub struct Res {
+        redirect: Redirect,
+      }
+    }
+
+    let res: Res = self.request(
+      Method::POST,
+      "/admin/api/2023-07/redirects.json",
+      move |b| b.json(&json!({ "redirect": redirect })),
+    )?;
+    Ok(res.into_inner())
+  }
+
+  fn update<V: Serialize>(&self, id: i64, value: V) -> ShopifyResult<Redirect> {
+    shopify_wrap! {
+      pub struct Res {
+        redirect: Redirect,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/redirects/{}.json", id);
+    let res: Res = self.request(Method::PUT, &path, move |b| {
+      b.json(&json!({ "redirect": value }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn delete(&self, id: i64) -> ShopifyResult<()> {
+    let path = format!("/admin/api/2023-07/redirects/{}.json", id);
+    self.request_no_content(Method::DELETE, &path, std::convert::identity)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  #[ignore]
+  fn test_list_redirects() {
+    let client = crate::client::get_test_client();
+    let page = client.list(&Default::default()).unwrap();
+    println!("{:#?}", page);
+  }
+}
@@ -0,0 +1,24 @@
+/// Redirects requests for `path` to `target`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Redirect {
+  pub id: i64,
+  /// The old path, e.g. `/products.php`.
+  pub path: String,
+  /// A path on the shop, e.g. `/products`, or a full URL.
+  pub target: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NewRedirect {
+  pub path: String,
+  pub target: String,
+}
+
+impl NewRedirect {
+  pub fn new(path: &str, target: &str) -> Self {
+    NewRedirect {
+      path: path.to_owned(),
+      target: target.to_owned(),
+    }
+  }
+}
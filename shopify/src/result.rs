@@ -1,3 +1,6 @@
+use crate::client::{GraphQLError, UserError};
+use crate::order::{Fulfillment, FulfillmentOrder};
+use crate::price_rule::DiscountCodeCreationJobStatus;
 use reqwest::StatusCode;
 use thiserror::Error;
 
@@ -30,6 +33,53 @@ pub enum ShopifyError {
 
   #[error("page_info parameter was not found in the link url")]
   PageInfoNotPresent,
+
+  #[error("fulfillment order {id} does not support action '{action}'")]
+  UnsupportedFulfillmentOrderAction { id: i64, action: &'static str },
+
+  #[error("{0} was not found in the order")]
+  LineItemNotFound(String),
+
+  #[error("invalid quantity {quantity} for {item}: must be positive")]
+  InvalidFulfillQuantity { item: String, quantity: i64 },
+
+  #[error("insufficient fulfillable quantity for {item}: {missing} missing")]
+  InsufficientFulfillableQuantity { item: String, missing: i64 },
+
+  /// A step of `OrderApi::fulfill` failed after fulfillment orders were moved or fulfillments
+  /// were created.
+  #[error(
+    "fulfill failed after moving {} fulfillment orders and creating {} fulfillments: {source}",
+    .moved_fulfillment_orders.len(),
+    .fulfillments.len()
+  )]
+  PartialFulfillment {
+    fulfillments: Vec<Fulfillment>,
+    moved_fulfillment_orders: Vec<FulfillmentOrder>,
+    #[source]
+    source: Box<ShopifyError>,
+  },
+
+  #[error("draft order {id} is still being completed")]
+  DraftOrderCompletionPending { id: i64 },
+
+  #[error("invalid {company} tracking number: {number}")]
+  InvalidTrackingNumber { company: String, number: String },
+
+  #[error("discount code batch {batch_id} did not complete in time, status: {status:?}")]
+  DiscountCodeBatchTimeout {
+    batch_id: i64,
+    status: DiscountCodeCreationJobStatus,
+  },
+
+  #[error("invalid value for metafield type '{type_}': {value}")]
+  InvalidMetafieldValue { type_: String, value: String },
+
+  #[error("graphql error: {0:?}")]
+  GraphQL(Vec<GraphQLError>),
+
+  #[error("user errors: {0:?}")]
+  UserErrors(Vec<UserError>),
 }
 
 impl ShopifyError {
@@ -41,6 +91,7 @@ impl ShopifyError {
         code == 429 || code == 500 || code == 503
       }
       ShopifyError::Io(_) => true,
+      ShopifyError::GraphQL(ref errors) => errors.iter().any(|e| e.code() == Some("THROTTLED")),
       _ => false,
     }
   }
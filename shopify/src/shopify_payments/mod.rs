@@ -0,0 +1,383 @@
+use crate::client::{Client, Method};
+use crate::pagination::{GetPage, Paginated};
+use crate::result::*;
+use crate::types::{DateTime, Utc};
+use chrono::NaiveDate;
+
+mod types;
+pub use self::types::*;
+
+request_query! {
+  pub struct GetPayoutListParams {
+    pub limit: Option<i64>,
+    pub since_id: Option<i64>,
+    pub last_id: Option<i64>,
+    pub status: Option<PayoutStatus>,
+    pub date: Option<NaiveDate>,
+    pub date_min: Option<NaiveDate>,
+    pub date_max: Option<NaiveDate>,
+  }
+}
+
+request_query! {
+  pub struct GetBalanceTransactionListParams {
+    pub limit: Option<i64>,
+    pub since_id: Option<i64>,
+    pub last_id: Option<i64>,
+    pub payout_id: Option<i64>,
+    pub payout_status: Option<PayoutStatus>,
+    pub test: Option<bool>,
+  }
+}
+
+request_query! {
+  pub struct GetDisputeListParams {
+    pub limit: Option<i64>,
+    pub since_id: Option<i64>,
+    pub last_id: Option<i64>,
+    pub status: Option<DisputeStatus>,
+    pub initiated_at: Option<DateTime<Utc>>,
+  }
+}
+
+pub trait ShopifyPaymentsApi {
+  /// Returns the current balance in each currency.
+  fn get_balance(&self) -> ShopifyResult<Vec<Balance>>;
+
+  fn list_payouts(&self, params: &GetPayoutListParams) -> ShopifyResult<Paginated<Vec<Payout>>>;
+
+  fn list_payouts_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<Payout>>>;
+
+  fn get_payout(&self, id: i64) -> ShopifyResult<Payout>;
+
+  /// Lists balance transactions. Set `payout_id` to list the transactions of a payout.
+  fn list_balance_transactions(
+    &self,
+    params: &GetBalanceTransactionListParams,
+  ) -> ShopifyResult<Paginated<Vec<BalanceTransaction>>>;
+
+  fn list_balance_transactions_page(
+    &self,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<BalanceTransaction>>>;
+
+  fn list_disputes(&self, params: &GetDisputeListParams) -> ShopifyResult<Paginated<Vec<Dispute>>>;
+
+  fn list_disputes_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<Dispute>>>;
+
+  fn get_dispute(&self, id: i64) -> ShopifyResult<Dispute>;
+
+  fn get_dispute_evidence(&self, dispute_id: i64) -> ShopifyResult<DisputeEvidence>;
+
+  fn update_dispute_evidence(
+    &self,
+    dispute_id: i64,
+    evidence: &UpdateDisputeEvidence,
+  ) -> ShopifyResult<DisputeEvidence>;
+}
+
+impl ShopifyPaymentsApi for Client {
+  fn get_balance(&self) -> ShopifyResult<Vec<Balance>> {
+    shopify_wrap! {
+      pub struct Res {
+        balance: Vec<Balance>,
+      }
+    }
+
+    let res: Res = self.request(
+      Method::GET,
+      "/admin/api/2023-07/shopify_payments/balance.json",
+      std::convert::identity,
+    )?;
+    Ok(res.into_inner())
+  }
+
+  fn list_payouts(&self, params: &GetPayoutListParams) -> ShopifyResult<Paginated<Vec<Payout>>> {
+    shopify_wrap! {
+      pub struct Res {
+        payouts: Vec<Payout>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/shopify_payments/payouts.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_payouts_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<Payout>>> {
+    shopify_wrap! {
+      pub struct Res {
+        payouts: Vec<Payout>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/shopify_payments/payouts.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn get_payout(&self, id: i64) -> ShopifyResult<Payout> {
+    shopify_wrap! {
+      pub struct Res {
+        payout: Payout,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/shopify_payments/payouts/{}.json", id);
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn list_balance_transactions(
+    &self,
+    params: &GetBalanceTransactionListParams,
+  ) -> ShopifyResult<Paginated<Vec<BalanceTransaction>>> {
+    shopify_wrap! {
+      pub struct Res {
+        transactions: Vec<BalanceTransaction>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/shopify_payments/balance/transactions.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_balance_transactions_page(
+    &self,
+    params: &GetPage,
+  ) -> ShopifyResult<Paginated<Vec<BalanceTransaction>>> {
+    shopify_wrap! {
+      pub struct Res {
+        transactions: Vec<BalanceTransaction>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/shopify_payments/balance/transactions.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_disputes(&self, params: &GetDisputeListParams) -> ShopifyResult<Paginated<Vec<Dispute>>> {
+    shopify_wrap! {
+      pub struct Res {
+        disputes: Vec<Dispute>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/shopify_payments/disputes.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_disputes_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<Dispute>>> {
+    shopify_wrap! {
+      pub struct Res {
+        disputes: Vec<Dispute>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/shopify_payments/disputes.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn get_dispute(&self, id: i64) -> ShopifyResult<Dispute> {
+    shopify_wrap! {
+      pub struct Res {
+        dispute: Dispute,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/shopify_payments/disputes/{}.json", id);
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn get_dispute_evidence(&self, dispute_id: i64) -> ShopifyResult<DisputeEvidence> {
+    shopify_wrap! {
+      pub struct Res {
+        dispute_evidence: DisputeEvidence,
+      }
+    }
+
+    let path = format!(
+      "/admin/api/2023-07/shopify_payments/disputes/{}/dispute_evidences.json",
+      dispute_id
+    );
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn update_dispute_evidence(
+    &self,
+    dispute_id: i64,
+    evidence: &UpdateDisputeEvidence,
+  ) -> ShopifyResult<DisputeEvidence> {
+    shopify_wrap! {
+      pub struct Res {
+        dispute_evidence: DisputeEvidence,
+      }
+    }
+
+    let path = format!(
+      "/admin/api/2023-07/shopify_payments/disputes/{}/dispute_evidences.json",
+      dispute_id
+    );
+    let res: Res = self.request(Method::PUT, &path, move |b| {
+      b.json(&json!({ "dispute_evidence": evidence }))
+    })?;
+    Ok(res.into_inner())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::client::ShopifyRequestQuery;
+  use crate::order::Money;
+
+  #[test]
+  fn test_deserialize_payouts() {
+    shopify_wrap! {
+      pub struct Res {
+        payouts: Vec<Payout>,
+      }
+    }
+
+    let payouts = serde_json::from_str::<Res>(
+      r##"
+    {
+      "payouts": [
+        {
+          "id": 623721858,
+          "status": "paid",
+          "date": "2012-11-12",
+          "currency": "USD",
+          "amount": "41.90",
+          "summary": {
+            "adjustments_fee_amount": "0.12",
+            "adjustments_gross_amount": "2.13",
+            "charges_fee_amount": "1.32",
+            "charges_gross_amount": "45.52",
+            "refunds_fee_amount": "-0.23",
+            "refunds_gross_amount": "-3.54",
+            "reserved_funds_fee_amount": "0.00",
+            "reserved_funds_gross_amount": "0.00",
+            "retried_payouts_fee_amount": "0.00",
+            "retried_payouts_gross_amount": "0.00"
+          }
+        }
+      ]
+    }
+    "##,
+    )
+    .unwrap()
+    .into_inner();
+    assert_eq!(payouts[0].status, PayoutStatus::Paid);
+    assert_eq!(payouts[0].amount_money(), Money::new("41.90", "USD"));
+  }
+
+  #[test]
+  fn test_deserialize_balance_transactions() {
+    shopify_wrap! {
+      pub struct Res {
+        transactions: Vec<BalanceTransaction>,
+      }
+    }
+
+    let transactions = serde_json::from_str::<Res>(
+      r##"
+    {
+      "transactions": [
+        {
+          "id": 699519475,
+          "type": "debit",
+          "test": false,
+          "payout_id": 623721858,
+          "payout_status": "paid",
+          "currency": "USD",
+          "amount": "-50.00",
+          "fee": "0.00",
+          "net": "-50.00",
+          "source_id": 460709370,
+          "source_type": "adjustment",
+          "source_order_id": null,
+          "source_order_transaction_id": null,
+          "processed_at": "2023-07-11T18:33:22-04:00"
+        },
+        {
+          "id": 77412310,
+          "type": "application_fee_refund",
+          "test": false,
+          "payout_id": 623721858,
+          "payout_status": "paid",
+          "currency": "USD",
+          "amount": "1.00",
+          "fee": "0.00",
+          "net": "1.00",
+          "source_id": null,
+          "source_type": null,
+          "source_order_id": null,
+          "source_order_transaction_id": null,
+          "processed_at": "2023-07-11T18:33:22-04:00"
+        }
+      ]
+    }
+    "##,
+    )
+    .unwrap()
+    .into_inner();
+    assert_eq!(transactions[0].type_, BalanceTransactionType::Debit);
+    assert_eq!(transactions[0].net_money(), Money::new("-50.00", "USD"));
+    assert_eq!(transactions[1].type_, BalanceTransactionType::Other);
+  }
+
+  #[test]
+  fn test_payout_list_params() {
+    let params = GetPayoutListParams {
+      status: Some(PayoutStatus::InTransit),
+      date_min: NaiveDate::from_ymd_opt(2023, 7, 1),
+      ..Default::default()
+    };
+    assert_eq!(
+      params.as_query_pairs(),
+      vec![
+        ("status".to_owned(), "in_transit".to_owned()),
+        ("date_min".to_owned(), "2023-07-01".to_owned()),
+      ]
+    );
+  }
+
+  #[test]
+  #[ignore]
+  fn test_list_payouts() {
+    let client = crate::client::get_test_client();
+    let page = client.list_payouts(&Default::default()).unwrap();
+    println!("{:#?}", page);
+  }
+}
@@ -0,0 +1,227 @@
+use crate::client::AsQueryValue;
+use crate::order::{Address, Money};
+use crate::types::{DateTime, Utc, Value};
+use chrono::NaiveDate;
+
+/// The Shopify Payments balance in one currency.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Balance {
+  pub amount: String,
+  pub currency: String,
+}
+
+impl Balance {
+  pub fn money(&self) -> Money {
+    Money::new(&self.amount, &self.currency)
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutStatus {
+  Scheduled,
+  InTransit,
+  Paid,
+  Failed,
+  Canceled,
+}
+
+impl AsQueryValue for PayoutStatus {
+  fn as_query_value(&self) -> String {
+    match *self {
+      PayoutStatus::Scheduled => "scheduled",
+      PayoutStatus::InTransit => "in_transit",
+      PayoutStatus::Paid => "paid",
+      PayoutStatus::Failed => "failed",
+      PayoutStatus::Canceled => "canceled",
+    }
+    .to_owned()
+  }
+}
+
+/// The fees and gross amounts of the transactions of a payout, by transaction type.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PayoutSummary {
+  pub adjustments_fee_amount: String,
+  pub adjustments_gross_amount: String,
+  pub charges_fee_amount: String,
+  pub charges_gross_amount: String,
+  pub refunds_fee_amount: String,
+  pub refunds_gross_amount: String,
+  pub reserved_funds_fee_amount: String,
+  pub reserved_funds_gross_amount: String,
+  pub retried_payouts_fee_amount: String,
+  pub retried_payouts_gross_amount: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Payout {
+  pub id: i64,
+  pub status: PayoutStatus,
+  pub date: NaiveDate,
+  /// The net amount deposited.
+  pub amount: String,
+  pub currency: String,
+  pub summary: Option<PayoutSummary>,
+}
+
+impl Payout {
+  pub fn amount_money(&self) -> Money {
+    Money::new(&self.amount, &self.currency)
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BalanceTransactionType {
+  Charge,
+  Refund,
+  Dispute,
+  Reserve,
+  Adjustment,
+  Credit,
+  Debit,
+  Payout,
+  PayoutFailure,
+  PayoutCancellation,
+  #[serde(other)]
+  Other,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BalanceTransaction {
+  pub id: i64,
+  #[serde(rename = "type")]
+  pub type_: BalanceTransactionType,
+  pub test: bool,
+  pub payout_id: Option<i64>,
+  pub payout_status: Option<PayoutStatus>,
+  pub currency: String,
+  pub amount: String,
+  pub fee: String,
+  pub net: String,
+  pub source_id: Option<i64>,
+  pub source_type: Option<String>,
+  pub source_order_id: Option<i64>,
+  pub source_order_transaction_id: Option<i64>,
+  pub processed_at: DateTime<Utc>,
+}
+
+impl BalanceTransaction {
+  pub fn amount_money(&self) -> Money {
+    Money::new(&self.amount, &self.currency)
+  }
+
+  pub fn fee_money(&self) -> Money {
+    Money::new(&self.fee, &self.currency)
+  }
+
+  pub fn net_money(&self) -> Money {
+    Money::new(&self.net, &self.currency)
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DisputeType {
+  Chargeback,
+  Inquiry,
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DisputeStatus {
+  NeedsResponse,
+  UnderReview,
+  ChargeRefunded,
+  Accepted,
+  Won,
+  Lost,
+}
+
+impl AsQueryValue for DisputeStatus {
+  fn as_query_value(&self) -> String {
+    match *self {
+      DisputeStatus::NeedsResponse => "needs_response",
+      DisputeStatus::UnderReview => "under_review",
+      DisputeStatus::ChargeRefunded => "charge_refunded",
+      DisputeStatus::Accepted => "accepted",
+      DisputeStatus::Won => "won",
+      DisputeStatus::Lost => "lost",
+    }
+    .to_owned()
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Dispute {
+  pub id: i64,
+  pub order_id: Option<i64>,
+  #[serde(rename = "type")]
+  pub type_: DisputeType,
+  pub amount: String,
+  pub currency: String,
+  /// e.g. `fraudulent` or `product_not_received`.
+  pub reason: String,
+  pub network_reason_code: Option<String>,
+  pub status: DisputeStatus,
+  pub evidence_due_by: Option<DateTime<Utc>>,
+  pub evidence_sent_on: Option<DateTime<Utc>>,
+  pub finalized_on: Option<NaiveDate>,
+  pub initiated_at: DateTime<Utc>,
+}
+
+impl Dispute {
+  pub fn amount_money(&self) -> Money {
+    Money::new(&self.amount, &self.currency)
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DisputeEvidence {
+  pub id: i64,
+  pub payments_dispute_id: i64,
+  pub access_activity_log: Option<String>,
+  pub cancellation_policy_disclosure: Option<String>,
+  pub cancellation_rebuttal: Option<String>,
+  pub refund_policy_disclosure: Option<String>,
+  pub refund_refusal_explanation: Option<String>,
+  pub uncategorized_text: Option<String>,
+  pub customer_first_name: Option<String>,
+  pub customer_last_name: Option<String>,
+  pub customer_email_address: Option<String>,
+  pub shipping_address: Option<Address>,
+  pub billing_address: Option<Address>,
+  pub product_description: Option<Value>,
+  #[serde(default)]
+  pub fulfillments: Vec<Value>,
+  pub dispute_evidence_files: Option<Value>,
+  pub submitted_by_merchant_on: Option<DateTime<Utc>>,
+  pub created_at: Option<DateTime<Utc>>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// The evidence fields to change. Set `submit_evidence` to submit the evidence to the bank.
+#[derive(Debug, Serialize, Default)]
+pub struct UpdateDisputeEvidence {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub access_activity_log: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cancellation_policy_disclosure: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cancellation_rebuttal: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub refund_policy_disclosure: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub refund_refusal_explanation: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub uncategorized_text: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub customer_first_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub customer_last_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub customer_email_address: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub submit_evidence: Option<bool>,
+}
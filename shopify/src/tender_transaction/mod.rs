@@ -0,0 +1,128 @@
+use crate::client::{Client, Method};
+use crate::pagination::{GetPage, Paginated};
+use crate::result::*;
+use crate::types::{DateTime, Utc};
+
+mod types;
+pub use self::types::*;
+
+request_query! {
+  pub struct GetTenderTransactionListParams {
+    pub limit: Option<i64>,
+    pub since_id: Option<i64>,
+    pub processed_at: Option<DateTime<Utc>>,
+    pub processed_at_min: Option<DateTime<Utc>>,
+    pub processed_at_max: Option<DateTime<Utc>>,
+    pub order: Option<String>,
+  }
+}
+
+pub trait TenderTransactionApi {
+  /// Lists tender transactions, e.g. with an `order` of `processed_at ASC`.
+  fn list(
+    &self,
+    params: &GetTenderTransactionListParams,
+  ) -> ShopifyResult<Paginated<Vec<TenderTransaction>>>;
+
+  fn list_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<TenderTransaction>>>;
+}
+
+impl TenderTransactionApi for Client {
+  fn list(
+    &self,
+    params: &GetTenderTransactionListParams,
+  ) -> ShopifyResult<Paginated<Vec<TenderTransaction>>> {
+    shopify_wrap! {
+      pub struct Res {
+        tender_transactions: Vec<TenderTransaction>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/tender_transactions.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+
+  fn list_page(&self, params: &GetPage) -> ShopifyResult<Paginated<Vec<TenderTransaction>>> {
+    shopify_wrap! {
+      pub struct Res {
+        tender_transactions: Vec<TenderTransaction>,
+      }
+    }
+
+    let res: Paginated<Res> = self.request_with_params_paginated(
+      Method::GET,
+      "/admin/api/2023-07/tender_transactions.json",
+      params,
+      std::convert::identity,
+    )?;
+    Ok(res.map(|p| p.into_inner()))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::order::Money;
+
+  #[test]
+  fn test_deserialize_tender_transactions() {
+    shopify_wrap! {
+      pub struct Res {
+        tender_transactions: Vec<TenderTransaction>,
+      }
+    }
+
+    let transactions = serde_json::from_str::<Res>(
+      r##"
+    {
+      "tender_transactions": [
+        {
+          "id": 1011222896,
+          "order_id": 450789469,
+          "amount": "250.94",
+          "currency": "USD",
+          "user_id": null,
+          "test": false,
+          "processed_at": "2023-07-10T18:33:22-04:00",
+          "remote_reference": "authorization-key",
+          "payment_details": {
+            "credit_card_number": "•••• •••• •••• 1",
+            "credit_card_company": "Bogus"
+          },
+          "payment_method": "credit_card"
+        },
+        {
+          "id": 1011222897,
+          "order_id": 450789469,
+          "amount": "-10.00",
+          "currency": "USD",
+          "user_id": null,
+          "test": false,
+          "processed_at": "2023-07-11T18:33:22-04:00",
+          "remote_reference": null,
+          "payment_details": null,
+          "payment_method": "other"
+        }
+      ]
+    }
+    "##,
+    )
+    .unwrap()
+    .into_inner();
+    assert_eq!(transactions[0].amount_money(), Money::new("250.94", "USD"));
+    assert!(transactions[1].payment_details.is_none());
+  }
+
+  #[test]
+  #[ignore]
+  fn test_list_tender_transactions() {
+    let client = crate::client::get_test_client();
+    let page = client.list(&Default::default()).unwrap();
+    println!("{:#?}", page);
+  }
+}
@@ -0,0 +1,31 @@
+use crate::order::Money;
+use crate::types::{DateTime, Utc};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TenderTransactionPaymentDetails {
+  pub credit_card_company: Option<String>,
+  /// The last digits of the card, e.g. `•••• •••• •••• 4242`.
+  pub credit_card_number: Option<String>,
+}
+
+/// A payment or refund of an order, from the perspective of the merchant's books.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TenderTransaction {
+  pub id: i64,
+  pub order_id: i64,
+  /// Negative for refunds.
+  pub amount: String,
+  pub currency: String,
+  pub user_id: Option<i64>,
+  pub test: bool,
+  pub processed_at: DateTime<Utc>,
+  pub remote_reference: Option<String>,
+  pub payment_method: Option<String>,
+  pub payment_details: Option<TenderTransactionPaymentDetails>,
+}
+
+impl TenderTransaction {
+  pub fn amount_money(&self) -> Money {
+    Money::new(&self.amount, &self.currency)
+  }
+}
@@ -0,0 +1,208 @@
+use crate::client::{Client, Method};
+use crate::result::*;
+
+mod types;
+pub use self::types::*;
+
+pub trait ThemeApi {
+  fn list_themes(&self) -> ShopifyResult<Vec<Theme>>;
+
+  fn get_theme(&self, id: i64) -> ShopifyResult<Theme>;
+
+  fn create_theme(&self, theme: &NewTheme) -> ShopifyResult<Theme>;
+
+  fn update_theme_role(&self, id: i64, role: ThemeRole) -> ShopifyResult<Theme>;
+
+  /// The main theme can not be deleted.
+  fn delete_theme(&self, id: i64) -> ShopifyResult<()>;
+}
+
+impl ThemeApi for Client {
+  fn list_themes(&self) -> ShopifyResult<Vec<Theme>> {
+    shopify_wrap! {
+      pub struct Res {
+        themes: Vec<Theme>,
+      }
+    }
+
+    let res: Res = self.request(
+      Method::GET,
+      "/admin/api/2023-07/themes.json",
+      std::convert::identity,
+    )?;
+    Ok(res.into_inner())
+  }
+
+  fn get_theme(&self, id: i64) -> ShopifyResult<Theme> {
+    shopify_wrap! {
+      pub struct Res {
+        theme: Theme,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/themes/{}.json", id);
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn create_theme(&self, theme: &NewTheme) -> ShopifyResult<Theme> {
+    shopify_wrap! {
+      pub struct Res {
+        theme: Theme,
+      }
+    }
+
+    let res: Res = self.request(Method::POST, "/admin/api/2023-07/themes.json", move |b| {
+      b.json(&json!({ "theme": theme }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn update_theme_role(&self, id: i64, role: ThemeRole) -> ShopifyResult<Theme> {
+    shopify_wrap! {
+      pub struct Res {
+        theme: Theme,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/themes/{}.json", id);
+    let res: Res = self.request(Method::PUT, &path, move |b| {
+      b.json(&json!({
+        "theme": {
+          "role": role,
+        }
+      }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn delete_theme(&self, id: i64) -> ShopifyResult<()> {
+    let path = format!("/admin/api/2023-07/themes/{}.json", id);
+    self.request_no_content(Method::DELETE, &path, std::convert::identity)
+  }
+}
+
+pub trait AssetApi {
+  /// Lists the assets of a theme, without their content.
+  fn list_assets(&self, theme_id: i64) -> ShopifyResult<Vec<Asset>>;
+
+  fn get_asset(&self, theme_id: i64, key: &str) -> ShopifyResult<Asset>;
+
+  /// Creates or replaces the asset at `key`.
+  fn put_asset(&self, theme_id: i64, key: &str, source: &AssetSource) -> ShopifyResult<Asset>;
+
+  fn delete_asset(&self, theme_id: i64, key: &str) -> ShopifyResult<()>;
+}
+
+impl AssetApi for Client {
+  fn list_assets(&self, theme_id: i64) -> ShopifyResult<Vec<Asset>> {
+    shopify_wrap! {
+      pub struct Res {
+        assets: Vec<Asset>,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/themes/{}/assets.json", theme_id);
+    let res: Res = self.request(Method::GET, &path, std::convert::identity)?;
+    Ok(res.into_inner())
+  }
+
+  fn get_asset(&self, theme_id: i64, key: &str) -> ShopifyResult<Asset> {
+    shopify_wrap! {
+      pub struct Res {
+        asset: Asset,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/themes/{}/assets.json", theme_id);
+    let res: Res = self.request_with_params(
+      Method::GET,
+      &path,
+      &("asset[key]", key),
+      std::convert::identity,
+    )?;
+    Ok(res.into_inner())
+  }
+
+  fn put_asset(&self, theme_id: i64, key: &str, source: &AssetSource) -> ShopifyResult<Asset> {
+    shopify_wrap! {
+      pub struct Res {
+        asset: Asset,
+      }
+    }
+
+    let path = format!("/admin/api/2023-07/themes/{}/assets.json", theme_id);
+    let res: Res = self.request(Method::PUT, &path, move |b| {
+      b.json(&json!({ "asset": source.to_json(key) }))
+    })?;
+    Ok(res.into_inner())
+  }
+
+  fn delete_asset(&self, theme_id: i64, key: &str) -> ShopifyResult<()> {
+    let path = format!("/admin/api/2023-07/themes/{}/assets.json", theme_id);
+    self.request_no_content(Method::DELETE, &path, |b| b.query(&[("asset[key]", key)]))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_deserialize_asset() {
+    shopify_wrap! {
+      pub struct Res {
+        asset: Asset,
+      }
+    }
+
+    let asset = serde_json::from_str::<Res>(
+      r##"
+    {
+      "asset": {
+        "key": "assets/bg-body.gif",
+        "public_url": "https://cdn.shopify.com/s/files/1/0005/4838/0009/t/1/assets/bg-body.gif?v=1689103018",
+        "created_at": "2010-07-12T15:31:50-04:00",
+        "updated_at": "2010-07-12T15:31:50-04:00",
+        "content_type": "image/gif",
+        "size": 42,
+        "checksum": "3fda56546f55fe3f83a5e1f5f0a4d0b5",
+        "theme_id": 828155753,
+        "attachment": "R0lGODlhAQABAPABAP///wAAACH5BAEKAAAALAAAAAABAAEAAAICRAEAOw=="
+      }
+    }
+    "##,
+    )
+    .unwrap()
+    .into_inner();
+    assert_eq!(asset.key, "assets/bg-body.gif");
+    assert!(asset.value.is_none());
+    assert!(asset.attachment.is_some());
+  }
+
+  #[test]
+  fn test_asset_source_json() {
+    assert_eq!(
+      AssetSource::SourceKey("assets/a.css".to_owned()).to_json("assets/b.css"),
+      json!({
+        "key": "assets/b.css",
+        "source_key": "assets/a.css",
+      })
+    );
+    assert_eq!(
+      AssetSource::Value("{}".to_owned()).to_json("config/settings_data.json"),
+      json!({
+        "key": "config/settings_data.json",
+        "value": "{}",
+      })
+    );
+  }
+
+  #[test]
+  #[ignore]
+  fn test_list_themes() {
+    let client = crate::client::get_test_client();
+    let themes = client.list_themes().unwrap();
+    println!("{:#?}", themes);
+  }
+}
@@ -0,0 +1,82 @@
+use crate::types::{DateTime, Utc};
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemeRole {
+  /// The theme is published. Setting a theme to `Main` unpublishes the current main theme.
+  Main,
+  Unpublished,
+  Demo,
+  Development,
+  #[serde(other)]
+  Other,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Theme {
+  pub id: i64,
+  pub name: String,
+  pub role: ThemeRole,
+  pub previewable: Option<bool>,
+  /// Whether the theme files are still being copied from `src`.
+  pub processing: Option<bool>,
+  pub theme_store_id: Option<i64>,
+  pub created_at: DateTime<Utc>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NewTheme {
+  pub name: String,
+  /// URL of a zip archive to create the theme from.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub src: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub role: Option<ThemeRole>,
+}
+
+/// A theme file. `value` or `attachment` is only set when the asset is fetched by key.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Asset {
+  /// The path of the asset in the theme, e.g. `templates/index.liquid`.
+  pub key: String,
+  pub theme_id: Option<i64>,
+  pub public_url: Option<String>,
+  /// The content of a text asset.
+  pub value: Option<String>,
+  /// The base64 encoded content of a binary asset.
+  pub attachment: Option<String>,
+  pub content_type: Option<String>,
+  pub size: Option<i64>,
+  /// MD5 checksum of the content.
+  pub checksum: Option<String>,
+  pub created_at: Option<DateTime<Utc>>,
+  pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// The content of an asset to create or replace.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AssetSource {
+  Value(String),
+  /// Base64 encoded binary content.
+  Attachment(String),
+  /// Copies the content of another asset in the same theme.
+  SourceKey(String),
+  /// Downloads the content from a URL.
+  Src(String),
+}
+
+impl AssetSource {
+  pub(crate) fn to_json(&self, key: &str) -> serde_json::Value {
+    let (field, value) = match *self {
+      AssetSource::Value(ref v) => ("value", v),
+      AssetSource::Attachment(ref v) => ("attachment", v),
+      AssetSource::SourceKey(ref v) => ("source_key", v),
+      AssetSource::Src(ref v) => ("src", v),
+    };
+    json!({
+      "key": key,
+      field: value,
+    })
+  }
+}
@@ -0,0 +1,232 @@
+//! Helpers for the `company`, `number` and `url` of [`TrackingInfo`](crate::order::TrackingInfo).
+//!
+//! Shopify only generates tracking links when `company` exactly matches one of its supported
+//! carriers, so carrier names coming from other systems ("ups", "Fed Ex", "canadapost") should be
+//! normalized first. For the most common carriers the tracking URL can also be built locally, for
+//! any other carrier it can be built from a URL template with [`tracking_url_from_template`].
+
+/// Tracking companies supported by Shopify.
+pub const SUPPORTED_COMPANIES: &[&str] = &[
+  "4PX",
+  "Amazon Logistics UK",
+  "Amazon Logistics US",
+  "APC",
+  "Asendia USA",
+  "Australia Post",
+  "Bluedart",
+  "Canada Post",
+  "Canpar",
+  "China Post",
+  "Correios",
+  "Couriers Please",
+  "DHL eCommerce",
+  "DHL eCommerce Asia",
+  "DHL Express",
+  "DPD",
+  "DPD Local",
+  "DPD UK",
+  "Eagle",
+  "FedEx",
+  "GLS",
+  "GLS (US)",
+  "Japan Post (EN)",
+  "La Poste",
+  "New Zealand Post",
+  "OnTrac",
+  "PostNL",
+  "PostNord",
+  "Purolator",
+  "Royal Mail",
+  "SF Express",
+  "Sendle",
+  "Singapore Post",
+  "StarTrack",
+  "TNT",
+  "UPS",
+  "USPS",
+  "Yanwen",
+  "YunExpress",
+];
+
+/// Carriers for which tracking numbers can be validated and tracking URLs built.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Carrier {
+  Ups,
+  FedEx,
+  Usps,
+  DhlExpress,
+  CanadaPost,
+}
+
+impl Carrier {
+  /// Recognizes common spellings of the carrier name.
+  pub fn from_name(name: &str) -> Option<Carrier> {
+    match simplify(name).as_str() {
+      "ups" | "unitedparcelservice" => Some(Carrier::Ups),
+      "fedex" | "federalexpress" | "fedexground" => Some(Carrier::FedEx),
+      "usps" | "unitedstatespostalservice" => Some(Carrier::Usps),
+      "dhl" | "dhlexpress" => Some(Carrier::DhlExpress),
+      "canadapost" | "postescanada" | "canadapostpostescanada" => Some(Carrier::CanadaPost),
+      _ => None,
+    }
+  }
+
+  /// The carrier's name in Shopify's supported `company` list.
+  pub fn company(&self) -> &'static str {
+    match *self {
+      Carrier::Ups => "UPS",
+      Carrier::FedEx => "FedEx",
+      Carrier::Usps => "USPS",
+      Carrier::DhlExpress => "DHL Express",
+      Carrier::CanadaPost => "Canada Post",
+    }
+  }
+
+  /// Checks `number` against the carrier's known tracking number formats.
+  ///
+  /// Spaces and dashes are ignored.
+  pub fn is_valid_tracking_number(&self, number: &str) -> bool {
+    let number = normalize_tracking_number(number);
+    let n = number.as_str();
+    match *self {
+      Carrier::Ups => {
+        (n.len() == 18 && n.starts_with("1Z") && is_alphanumeric(&n[2..]))
+          || (n.len() == 11 && n.starts_with('T') && is_digits(&n[1..]))
+      }
+      Carrier::FedEx => is_digits(n) && [12, 15, 20, 22].contains(&n.len()),
+      Carrier::Usps => {
+        (is_digits(n) && [20, 22, 26, 30, 34].contains(&n.len())) || is_s10(n, Some("US"))
+      }
+      Carrier::DhlExpress => is_digits(n) && (n.len() == 10 || n.len() == 11),
+      Carrier::CanadaPost => {
+        (is_digits(n) && (n.len() == 12 || n.len() == 16)) || is_s10(n, Some("CA"))
+      }
+    }
+  }
+
+  pub fn tracking_url(&self, number: &str) -> String {
+    let number = normalize_tracking_number(number);
+    match *self {
+      Carrier::Ups => format!("https://www.ups.com/track?tracknum={}", number),
+      Carrier::FedEx => format!("https://www.fedex.com/fedextrack/?trknbr={}", number),
+      Carrier::Usps => format!(
+        "https://tools.usps.com/go/TrackConfirmAction?tLabels={}",
+        number
+      ),
+      Carrier::DhlExpress => format!(
+        "https://www.dhl.com/en/express/tracking.html?AWB={}&brand=DHL",
+        number
+      ),
+      Carrier::CanadaPost => format!(
+        "https://www.canadapost-postescanada.ca/track-reperage/en#/search?searchFor={}",
+        number
+      ),
+    }
+  }
+}
+
+/// Maps `name` to the matching entry of [`SUPPORTED_COMPANIES`], ignoring case and punctuation.
+///
+/// Returns the trimmed `name` unchanged for carriers Shopify doesn't support.
+pub fn normalize_company(name: &str) -> String {
+  if let Some(carrier) = Carrier::from_name(name) {
+    return carrier.company().to_owned();
+  }
+  let simplified = simplify(name);
+  SUPPORTED_COMPANIES
+    .iter()
+    .find(|company| simplify(company) == simplified)
+    .map(|company| company.to_string())
+    .unwrap_or_else(|| name.trim().to_owned())
+}
+
+/// Builds the tracking URL of `number` if the carrier is known.
+pub fn tracking_url(company: &str, number: &str) -> Option<String> {
+  Carrier::from_name(company).map(|carrier| carrier.tracking_url(number))
+}
+
+/// Builds a tracking URL by replacing `{number}` in `template`, e.g.
+/// `https://track.example.com/?n={number}`.
+pub fn tracking_url_from_template(template: &str, number: &str) -> String {
+  template.replace("{number}", &normalize_tracking_number(number))
+}
+
+/// Removes whitespace and dashes, and uppercases the tracking number.
+pub fn normalize_tracking_number(number: &str) -> String {
+  number
+    .chars()
+    .filter(|c| !c.is_whitespace() && *c != '-')
+    .flat_map(char::to_uppercase)
+    .collect()
+}
+
+fn simplify(name: &str) -> String {
+  name
+    .chars()
+    .filter(|c| c.is_alphanumeric())
+    .flat_map(char::to_lowercase)
+    .collect()
+}
+
+fn is_digits(s: &str) -> bool {
+  !s.is_empty() && s.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_alphanumeric(s: &str) -> bool {
+  !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// UPU S10 international format, e.g. `RR123456785CA`.
+fn is_s10(s: &str, country: Option<&str>) -> bool {
+  s.is_ascii()
+    && s.len() == 13
+    && s[..2].chars().all(|c| c.is_ascii_uppercase())
+    && is_digits(&s[2..11])
+    && s[11..].chars().all(|c| c.is_ascii_uppercase())
+    && country.map(|country| &s[11..] == country).unwrap_or(true)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_normalize_company() {
+    assert_eq!(normalize_company("ups"), "UPS");
+    assert_eq!(normalize_company("Fed Ex"), "FedEx");
+    assert_eq!(normalize_company("DHL"), "DHL Express");
+    assert_eq!(normalize_company("canadapost"), "Canada Post");
+    assert_eq!(normalize_company("royal mail"), "Royal Mail");
+    assert_eq!(normalize_company(" Acme Freight "), "Acme Freight");
+  }
+
+  #[test]
+  fn test_tracking_number_formats() {
+    assert!(Carrier::Ups.is_valid_tracking_number("1Z 999 AA1 01 2345 6784"));
+    assert!(!Carrier::Ups.is_valid_tracking_number("1Z999AA1"));
+    assert!(Carrier::FedEx.is_valid_tracking_number("123456789012"));
+    assert!(!Carrier::FedEx.is_valid_tracking_number("12345678901A"));
+    assert!(Carrier::Usps.is_valid_tracking_number("9400 1000 0000 0000 0000 00"));
+    assert!(Carrier::Usps.is_valid_tracking_number("EC123456785US"));
+    assert!(Carrier::DhlExpress.is_valid_tracking_number("1234567890"));
+    assert!(Carrier::CanadaPost.is_valid_tracking_number("1234567890123456"));
+    assert!(Carrier::CanadaPost.is_valid_tracking_number("rr123456785ca"));
+    assert!(!Carrier::CanadaPost.is_valid_tracking_number("RR123456785US"));
+    // 13 bytes, but not 13 characters.
+    assert!(!Carrier::Usps.is_valid_tracking_number("AÉ1234567890"));
+    assert!(!Carrier::CanadaPost.is_valid_tracking_number("AÉ1234567890"));
+  }
+
+  #[test]
+  fn test_tracking_url() {
+    assert_eq!(
+      tracking_url("UPS", "1z999aa10123456784").as_deref(),
+      Some("https://www.ups.com/track?tracknum=1Z999AA10123456784")
+    );
+    assert_eq!(tracking_url("Acme Freight", "123"), None);
+    assert_eq!(
+      tracking_url_from_template("https://track.example.com/?n={number}", "ab 123"),
+      "https://track.example.com/?n=AB123"
+    );
+  }
+}